@@ -0,0 +1,171 @@
+//! Uses the tab bar with an application-defined theme type instead of
+//! `iced::Theme`.
+
+use iced::{
+    Color, Element, Length,
+    theme::{self, Mode, Palette, palette::Extended},
+    widget::{Column, Container, Text, container, scrollable, text},
+};
+
+use iced_fonts::CODICON_FONT_BYTES;
+use iced_tabs::{StyleArgs, StyleFn, TabBar, TabLabel, from_palette};
+
+fn main() -> iced::Result {
+    iced::application(App::default, App::update, App::view)
+        .theme(App::theme)
+        .font(CODICON_FONT_BYTES)
+        .run()
+}
+
+/// A theme that only chooses colors; every widget style is derived from its
+/// palette.
+struct Solarized {
+    palette: Palette,
+    extended: Extended,
+}
+
+impl Solarized {
+    fn new() -> Self {
+        let palette = Palette {
+            background: Color::from_rgb8(0xfd, 0xf6, 0xe3),
+            text: Color::from_rgb8(0x58, 0x6e, 0x75),
+            primary: Color::from_rgb8(0x26, 0x8b, 0xd2),
+            success: Color::from_rgb8(0x85, 0x99, 0x00),
+            warning: Color::from_rgb8(0xb5, 0x89, 0x00),
+            danger: Color::from_rgb8(0xdc, 0x32, 0x2f),
+        };
+        Self {
+            palette,
+            extended: Extended::generate(palette),
+        }
+    }
+}
+
+impl theme::Base for Solarized {
+    fn default(_preference: Mode) -> Self {
+        Self::new()
+    }
+
+    fn mode(&self) -> Mode {
+        Mode::Light
+    }
+
+    fn base(&self) -> theme::Style {
+        theme::Style {
+            background_color: self.palette.background,
+            text_color: self.palette.text,
+        }
+    }
+
+    fn palette(&self) -> Option<Palette> {
+        Some(self.palette)
+    }
+
+    fn name(&self) -> &str {
+        "Solarized"
+    }
+}
+
+impl iced_tabs::Catalog for Solarized {
+    type Class<'a> = StyleFn<'a, Self, iced_tabs::Style>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(|theme, args| from_palette(&theme.extended, args))
+    }
+
+    fn style(&self, class: &Self::Class<'_>, args: StyleArgs) -> iced_tabs::Style {
+        class(self, args)
+    }
+}
+
+// The tab bar lays out its labels, strip and tooltips with iced's own
+// widgets, so the theme needs their catalogs too.
+
+impl text::Catalog for Solarized {
+    type Class<'a> = ();
+
+    fn default<'a>() -> Self::Class<'a> {}
+
+    fn style(&self, _class: &Self::Class<'_>) -> text::Style {
+        text::Style::default()
+    }
+}
+
+impl container::Catalog for Solarized {
+    type Class<'a> = ();
+
+    fn default<'a>() -> Self::Class<'a> {}
+
+    fn style(&self, _class: &Self::Class<'_>) -> container::Style {
+        container::Style::default()
+    }
+}
+
+impl scrollable::Catalog for Solarized {
+    type Class<'a> = ();
+
+    fn default<'a>() -> Self::Class<'a> {}
+
+    fn style(&self, _class: &Self::Class<'_>, _status: scrollable::Status) -> scrollable::Style {
+        let rail = scrollable::Rail {
+            background: Some(self.extended.background.weak.color.into()),
+            border: iced::border::rounded(2),
+            scroller: scrollable::Scroller {
+                background: self.extended.primary.base.color.into(),
+                border: iced::border::rounded(2),
+            },
+        };
+        scrollable::Style {
+            container: container::Style::default(),
+            vertical_rail: rail,
+            horizontal_rail: rail,
+            gap: None,
+            auto_scroll: scrollable::AutoScroll {
+                background: self.extended.background.base.color.into(),
+                border: iced::Border::default(),
+                shadow: iced::Shadow::default(),
+                icon: self.palette.text,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    TabSelected(usize),
+}
+
+#[derive(Default)]
+struct App {
+    active_tab: usize,
+}
+
+impl App {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::TabSelected(index) => self.active_tab = index,
+        }
+    }
+
+    fn theme(&self) -> Solarized {
+        Solarized::new()
+    }
+
+    fn view(&self) -> Element<'_, Message, Solarized> {
+        let tab_bar = ["Base", "Accents", "Contrast"]
+            .into_iter()
+            .enumerate()
+            .fold(TabBar::new(Message::TabSelected), |bar, (i, name)| {
+                bar.push(i, TabLabel::Text(name.to_owned()))
+            })
+            .set_active_tab(&self.active_tab);
+
+        Column::new()
+            .push(tab_bar)
+            .push(
+                Container::new(Text::new(format!("Tab {}", self.active_tab + 1)))
+                    .center(Length::Fill),
+            )
+            .into()
+    }
+}
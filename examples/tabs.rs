@@ -33,7 +33,8 @@ fn main() -> iced::Result {
 enum Message {
     TabSelected(usize),
     TabClosed(usize),
-    TabReordered(usize, usize),
+    AllTabsClosed,
+    TabReordered(Vec<usize>),
     TabLabelInputChanged(String),
     TabContentInputChanged(String),
     NewTab,
@@ -46,8 +47,10 @@ enum Message {
     TabHeightChanged(f32),
     CloseSpacingChanged(f32),
     IconSpacingChanged(f32),
+    VerticalIconSpacingChanged(f32),
     ShowCloseButtonToggled(bool),
     ReorderableToggled(bool),
+    AutoHeightToggled(bool),
     LabelTypeChanged(LabelTypeChoice),
     IconPositionChanged(PositionChoice),
     TooltipDelayChanged(f32),
@@ -57,6 +60,7 @@ enum Message {
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum ScrollModeChoice {
     Floating,
+    FloatingAutoHide,
     Below,
     NoScrollbar,
 }
@@ -65,6 +69,7 @@ impl fmt::Display for ScrollModeChoice {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ScrollModeChoice::Floating => write!(f, "Floating"),
+            ScrollModeChoice::FloatingAutoHide => write!(f, "Floating (auto-hide)"),
             ScrollModeChoice::Below => write!(f, "Below"),
             ScrollModeChoice::NoScrollbar => write!(f, "No Scrollbar"),
         }
@@ -75,6 +80,7 @@ impl From<ScrollModeChoice> for ScrollMode {
     fn from(c: ScrollModeChoice) -> Self {
         match c {
             ScrollModeChoice::Floating => ScrollMode::Floating,
+            ScrollModeChoice::FloatingAutoHide => ScrollMode::FloatingAutoHide,
             ScrollModeChoice::Below => ScrollMode::Below(4.0.into()),
             ScrollModeChoice::NoScrollbar => ScrollMode::NoScrollbar,
         }
@@ -84,6 +90,7 @@ impl From<ScrollModeChoice> for ScrollMode {
 fn scroll_mode_to_choice(mode: &ScrollMode) -> ScrollModeChoice {
     match mode {
         ScrollMode::Floating => ScrollModeChoice::Floating,
+        ScrollMode::FloatingAutoHide => ScrollModeChoice::FloatingAutoHide,
         ScrollMode::Below(_) => ScrollModeChoice::Below,
         ScrollMode::NoScrollbar => ScrollModeChoice::NoScrollbar,
     }
@@ -157,8 +164,10 @@ struct TabBarExample {
     tab_height: f32,
     close_spacing: f32,
     icon_spacing: f32,
+    vertical_icon_spacing: f32,
     show_close_button: bool,
     reorderable: bool,
+    auto_height: bool,
     label_type: LabelTypeChoice,
     icon_position: PositionChoice,
     tooltip_delay_ms: f32,
@@ -180,8 +189,10 @@ impl Default for TabBarExample {
             tab_height: 35.0,
             close_spacing: 15.0,
             icon_spacing: 0.0,
+            vertical_icon_spacing: 0.0,
             show_close_button: true,
             reorderable: true,
+            auto_height: false,
             label_type: LabelTypeChoice::default(),
             icon_position: PositionChoice::default(),
             tooltip_delay_ms: 700.0,
@@ -203,18 +214,19 @@ impl TabBarExample {
                     self.active_tab.min(self.tabs.len() - 1)
                 };
             }
-            Message::TabReordered(from, to) => {
-                if from < self.tabs.len() && to < self.tabs.len() {
-                    let tab = self.tabs.remove(from);
-                    self.tabs.insert(to, tab);
+            Message::AllTabsClosed => {
+                self.tabs.clear();
+                self.active_tab = 0;
+            }
+            Message::TabReordered(order) => {
+                // Tab ids are their indices in `self.tabs`, so the new order
+                // maps directly onto the old positions.
+                if order.len() == self.tabs.len() {
+                    self.tabs = order.iter().map(|&i| self.tabs[i].clone()).collect();
 
                     // Keep the active tab tracking the same logical tab.
-                    if self.active_tab == from {
-                        self.active_tab = to;
-                    } else if from < self.active_tab && to >= self.active_tab {
-                        self.active_tab = self.active_tab.saturating_sub(1);
-                    } else if from > self.active_tab && to <= self.active_tab {
-                        self.active_tab = (self.active_tab + 1).min(self.tabs.len() - 1);
+                    if let Some(active) = order.iter().position(|&i| i == self.active_tab) {
+                        self.active_tab = active;
                     }
                 }
             }
@@ -243,10 +255,12 @@ impl TabBarExample {
             Message::TabHeightChanged(v) => self.tab_height = v,
             Message::CloseSpacingChanged(v) => self.close_spacing = v,
             Message::IconSpacingChanged(v) => self.icon_spacing = v,
+            Message::VerticalIconSpacingChanged(v) => self.vertical_icon_spacing = v,
 
             // Toggles
             Message::ShowCloseButtonToggled(v) => self.show_close_button = v,
             Message::ReorderableToggled(v) => self.reorderable = v,
+            Message::AutoHeightToggled(v) => self.auto_height = v,
 
             // Enum pick lists
             Message::LabelTypeChanged(v) => self.label_type = v,
@@ -283,6 +297,7 @@ impl TabBarExample {
                 pick_list(
                     [
                         ScrollModeChoice::Floating,
+                        ScrollModeChoice::FloatingAutoHide,
                         ScrollModeChoice::Below,
                         ScrollModeChoice::NoScrollbar,
                     ],
@@ -330,6 +345,12 @@ impl TabBarExample {
                     .label("Reorderable")
                     .size(20.0),
             )
+            .push(
+                Toggler::new(self.auto_height)
+                    .on_toggle(Message::AutoHeightToggled)
+                    .label("Auto height")
+                    .size(20.0),
+            )
             .align_y(Alignment::Center)
             .padding(10.0)
             .spacing(15.0);
@@ -386,12 +407,19 @@ impl TabBarExample {
                 Message::CloseSpacingChanged,
             ))
             .push(slider_control(
-                "Icon spacing",
+                "Icon spacing (left/right)",
                 self.icon_spacing,
                 0.0,
                 30.0,
                 Message::IconSpacingChanged,
             ))
+            .push(slider_control(
+                "Icon spacing (top/bottom)",
+                self.vertical_icon_spacing,
+                0.0,
+                30.0,
+                Message::VerticalIconSpacingChanged,
+            ))
             .push(slider_control(
                 "Tooltip delay",
                 self.tooltip_delay_ms,
@@ -428,18 +456,22 @@ impl TabBarExample {
             .icon_size(self.icon_size)
             .close_size(self.close_size)
             .height(self.tab_height)
+            .auto_height(self.auto_height)
             .close_spacing(self.close_spacing)
-            .icon_spacing(self.icon_spacing)
+            .icon_text_spacing(self.icon_spacing, self.vertical_icon_spacing)
             .set_position(self.icon_position.into())
             .scroll_mode(self.scroll_mode)
             .tooltip_delay(Duration::from_millis(self.tooltip_delay_ms as u64))
             .style(cool);
 
         if self.show_close_button {
-            tab_bar = tab_bar.on_close(Message::TabClosed);
+            tab_bar = tab_bar
+                .on_close(Message::TabClosed)
+                .on_close_all(|| Message::AllTabsClosed)
+                .close_tooltip("Close tab");
         }
         if self.reorderable {
-            tab_bar = tab_bar.on_reorder(Message::TabReordered);
+            tab_bar = tab_bar.on_reorder_ids(Message::TabReordered);
         }
 
         // -- Content area -------------------------------------------------------
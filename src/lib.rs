@@ -4,6 +4,10 @@ mod tab_bar;
 
 pub use {
     style::*,
-    tab::TabLabel,
-    tab_bar::{Position, ScrollMode, TabBar},
+    tab::{CloseIconRenderer, TabLabel},
+    tab_bar::{
+        CloseIconSource, Density, OverflowBehavior, Position, ReselectBehavior, ScrollConfig,
+        ScrollEdge, ScrollMode, ScrollbarStyle, TabBar, TabBarSnapshot, TabDragChannel,
+        TooltipAnchor, TooltipContent, tab_at,
+    },
 };
@@ -1,6 +1,8 @@
 //! Style and theme for the [`TabBar`](crate::TabBar).
 
-use iced::{Background, Color, Padding, Shadow, Theme, border::Radius};
+use iced::{
+    Background, Color, Padding, Shadow, Theme, Vector, border::Radius, theme::palette::Extended,
+};
 
 /// Combined style used by the [`TabBar`](crate::TabBar).
 #[derive(Clone, Copy, Debug, Default)]
@@ -11,6 +13,8 @@ pub struct Style {
     pub tab: TabStyle,
     /// Style of tab tooltips.
     pub tooltip: TooltipStyle,
+    /// Style of section headers.
+    pub section: SectionStyle,
 }
 
 /// The appearance of the outer tab bar container.
@@ -26,6 +30,13 @@ pub struct BarStyle {
     pub border_radius: Radius,
     /// Shadow applied to the outer bar.
     pub shadow: Shadow,
+    /// The color and width of a line along the bottom of the bar.
+    ///
+    /// The tabs are drawn over it, and the active tab breaks through it when
+    /// it reaches the bottom of the bar, so it looks connected to the content
+    /// below. An active tab [`indicator`](TabStyle::indicator) stays visible
+    /// in the gap. When `None`, no line is drawn.
+    pub baseline: Option<(Color, f32)>,
 }
 
 impl Default for BarStyle {
@@ -36,6 +47,7 @@ impl Default for BarStyle {
             border_width: 0.0,
             border_radius: Radius::default(),
             shadow: Shadow::default(),
+            baseline: None,
         }
     }
 }
@@ -53,14 +65,39 @@ pub struct TabStyle {
     pub border_radius: Radius,
     /// The icon color of the tab labels.
     pub icon_color: Color,
-    /// The background of the close icon.
-    pub icon_background: Option<Background>,
-    /// Border radius of the close icon.
-    pub icon_border_radius: Radius,
+    /// The background of the close button while it is hovered.
+    pub close_hover_background: Option<Background>,
+    /// The color of the close icon while it is hovered.
+    ///
+    /// When `None`, the close icon keeps the tab's text color.
+    pub close_hover_icon_color: Option<Color>,
+    /// The border radius of the close button's hover highlight.
+    pub close_border_radius: Radius,
     /// The text color of the tab labels.
     pub text_color: Color,
     /// Shadow applied to each tab.
     pub shadow: Shadow,
+    /// The color of the focus ring drawn around the tab.
+    ///
+    /// When `None`, no focus ring is drawn.
+    pub focus_border_color: Option<Color>,
+    /// The divider drawn in the gap between neighbouring tabs.
+    pub separator: SeparatorStyle,
+    /// The line drawn along the bottom of the active tab.
+    ///
+    /// Only read from the [`Status::Active`] style.
+    pub indicator: IndicatorStyle,
+    /// The color of the part of a label matching the
+    /// [`filter`](crate::TabBar::filter).
+    ///
+    /// When `None`, the match keeps the tab's text color.
+    pub filter_match_color: Option<Color>,
+    /// The background of the placeholder left in the row where the dragged
+    /// tab will land.
+    ///
+    /// Only read from the [`Status::Dragging`] style. When `None`, the gap
+    /// is left empty.
+    pub drag_placeholder_background: Option<Background>,
 }
 
 impl Default for TabStyle {
@@ -71,10 +108,55 @@ impl Default for TabStyle {
             border_radius: Radius::new(5.0),
             border_width: 1.0,
             icon_color: [0.5, 0.5, 0.5].into(),
-            icon_background: Some(Background::Color(Color::from_rgba(1.0, 0.0, 0.0, 0.9))),
-            icon_border_radius: 4.0.into(),
+            close_hover_background: Some(Background::Color(Color::from_rgba(1.0, 0.0, 0.0, 0.9))),
+            close_hover_icon_color: None,
+            close_border_radius: 4.0.into(),
             text_color: [0.9, 0.9, 0.9].into(),
             shadow: Shadow::default(),
+            focus_border_color: None,
+            separator: SeparatorStyle::default(),
+            indicator: IndicatorStyle::default(),
+            filter_match_color: None,
+            drag_placeholder_background: None,
+        }
+    }
+}
+
+/// The appearance of the dividers between tabs.
+///
+/// Dividers are hidden next to active, hovered, pressed and dragged tabs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SeparatorStyle {
+    /// The color of the divider.
+    pub color: Color,
+    /// The width of the divider. `0.0` draws no divider.
+    pub width: f32,
+}
+
+impl Default for SeparatorStyle {
+    fn default() -> Self {
+        Self {
+            color: Color::from_rgba(0.5, 0.5, 0.5, 0.5),
+            width: 0.0,
+        }
+    }
+}
+
+/// The appearance of section headers pushed with
+/// [`TabBar::push_section`](crate::TabBar::push_section).
+#[derive(Clone, Copy, Debug)]
+pub struct SectionStyle {
+    /// The background of the section header.
+    pub background: Option<Background>,
+    /// The text color of the section header.
+    pub text_color: Color,
+}
+
+impl Default for SectionStyle {
+    fn default() -> Self {
+        Self {
+            background: None,
+            text_color: Color::from_rgb(0.6, 0.6, 0.6),
         }
     }
 }
@@ -94,6 +176,14 @@ pub struct TooltipStyle {
     pub border_color: Color,
     /// The padding inside the tooltip.
     pub padding: Padding,
+    /// The maximum width of the tooltip text before it wraps.
+    ///
+    /// When `None`, the text wraps at half the window width.
+    pub max_width: Option<f32>,
+    /// Whether to draw a small triangle pointing at the hovered tab.
+    pub pointer: bool,
+    /// The height of the pointer triangle (its base is twice as wide).
+    pub pointer_size: f32,
 }
 
 impl Default for TooltipStyle {
@@ -105,10 +195,86 @@ impl Default for TooltipStyle {
             border_width: 1.0,
             border_color: Color::from_rgba(0.4, 0.4, 0.4, 0.8),
             padding: Padding::new(6.0).left(10.0).right(10.0),
+            max_width: None,
+            pointer: false,
+            pointer_size: 6.0,
         }
     }
 }
 
+/// The appearance of the line marking the active tab.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IndicatorStyle {
+    /// The color of the line.
+    pub color: Color,
+    /// The thickness of the line. `0.0` draws no line.
+    pub width: f32,
+}
+
+impl Default for IndicatorStyle {
+    fn default() -> Self {
+        Self {
+            color: Color::from_rgb(0.3, 0.5, 0.9),
+            width: 0.0,
+        }
+    }
+}
+
+/// The appearance of the tab that follows the cursor while dragging.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DragOverlayStyle {
+    /// Opacity multiplier applied to all colors of the dragged tab (`0.0`–`1.0`).
+    pub opacity: f32,
+    /// Scale factor applied to the dragged tab around its center.
+    pub scale: f32,
+}
+
+impl Default for DragOverlayStyle {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            scale: 1.0,
+        }
+    }
+}
+
+impl Style {
+    /// Returns a copy of the tab and bar colors with their alpha scaled by `factor`.
+    #[must_use]
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        self.bar.background = self.bar.background.map(|bg| bg.scale_alpha(factor));
+        self.bar.border_color = self.bar.border_color.map(|c| c.scale_alpha(factor));
+        self.bar.baseline = self
+            .bar
+            .baseline
+            .map(|(color, width)| (color.scale_alpha(factor), width));
+        self.tab.background = self.tab.background.scale_alpha(factor);
+        self.tab.border_color = self.tab.border_color.scale_alpha(factor);
+        self.tab.icon_color = self.tab.icon_color.scale_alpha(factor);
+        self.tab.text_color = self.tab.text_color.scale_alpha(factor);
+        self.tab.close_hover_background = self
+            .tab
+            .close_hover_background
+            .map(|bg| bg.scale_alpha(factor));
+        self.tab.close_hover_icon_color = self
+            .tab
+            .close_hover_icon_color
+            .map(|c| c.scale_alpha(factor));
+        self.tab.shadow.color = self.tab.shadow.color.scale_alpha(factor);
+        self.tab.focus_border_color = self.tab.focus_border_color.map(|c| c.scale_alpha(factor));
+        self.tab.separator.color = self.tab.separator.color.scale_alpha(factor);
+        self.tab.indicator.color = self.tab.indicator.color.scale_alpha(factor);
+        self.tab.filter_match_color = self.tab.filter_match_color.map(|c| c.scale_alpha(factor));
+        self.tab.drag_placeholder_background = self
+            .tab
+            .drag_placeholder_background
+            .map(|bg| bg.scale_alpha(factor));
+        self.section.background = self.section.background.map(|bg| bg.scale_alpha(factor));
+        self.section.text_color = self.section.text_color.scale_alpha(factor);
+        self
+    }
+}
+
 /// The interaction status of a tab, used to select the appropriate style.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
@@ -120,12 +286,90 @@ pub enum Status {
     Hovered,
     /// The tab is currently being dragged.
     Dragging,
+    /// The mouse button or finger is held down on the tab.
+    Pressed,
+    /// The tab has keyboard focus but is not the active tab.
+    Focused,
 }
 
-/// A closure that maps a theme and status to a [`Style`].
-pub type StyleFn<'a, Theme, Style> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+/// What a [`Style`] is requested for: the status of a tab and where it sits
+/// in the bar.
+///
+/// Styles that don't belong to a single tab, such as the bar, section
+/// headers or tooltips, are requested with [`StyleArgs::new`], so `total` is
+/// `0` and the tab is neither first nor last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StyleArgs {
+    /// The status of the tab.
+    pub status: Status,
+    /// Position of the tab in the bar, from the left.
+    pub index: usize,
+    /// Number of tabs in the bar.
+    pub total: usize,
+    /// Whether the tab is the leftmost tab of the bar.
+    pub is_first: bool,
+    /// Whether the tab is the rightmost tab of the bar.
+    pub is_last: bool,
+}
+
+impl StyleArgs {
+    /// Creates the arguments for the given status, without a tab position.
+    #[must_use]
+    pub fn new(status: Status) -> Self {
+        Self {
+            status,
+            index: 0,
+            total: 0,
+            is_first: false,
+            is_last: false,
+        }
+    }
+
+    /// Places the tab at `index` among `total` tabs.
+    #[must_use]
+    pub fn at(self, index: usize, total: usize) -> Self {
+        Self {
+            index,
+            total,
+            is_first: index == 0,
+            is_last: index + 1 == total,
+            ..self
+        }
+    }
+}
+
+impl From<Status> for StyleArgs {
+    fn from(status: Status) -> Self {
+        Self::new(status)
+    }
+}
+
+/// A closure that maps a theme and [`StyleArgs`] to a [`Style`].
+pub type StyleFn<'a, Theme, Style> = Box<dyn Fn(&Theme, StyleArgs) -> Style + 'a>;
 
 /// The Catalog of a [`TabBar`](crate::TabBar).
+///
+/// Implemented for [`iced::Theme`]. A custom theme type implements it the
+/// same way, with [`StyleFn`] as its class and [`from_palette`] for the
+/// default look, so the theme only has to provide its colors:
+///
+/// ```ignore
+/// impl iced_tabs::Catalog for MyTheme {
+///     type Class<'a> = iced_tabs::StyleFn<'a, Self, iced_tabs::Style>;
+///
+///     fn default<'a>() -> Self::Class<'a> {
+///         Box::new(|theme, args| iced_tabs::from_palette(&theme.extended, args))
+///     }
+///
+///     fn style(&self, class: &Self::Class<'_>, args: iced_tabs::StyleArgs) -> iced_tabs::Style {
+///         class(self, args)
+///     }
+/// }
+/// ```
+///
+/// The [`TabBar`](crate::TabBar) also needs the theme to implement iced's
+/// `text`, `scrollable` and `container` catalogs. The `custom_theme` example
+/// shows a complete theme.
 pub trait Catalog {
     /// The style class type.
     type Class<'a>;
@@ -133,8 +377,8 @@ pub trait Catalog {
     /// The default class produced by the [`Catalog`].
     fn default<'a>() -> Self::Class<'a>;
 
-    /// The [`Style`] of a class with the given status.
-    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+    /// The [`Style`] of a class for the given status and tab position.
+    fn style(&self, class: &Self::Class<'_>, args: StyleArgs) -> Style;
 }
 
 impl Catalog for Theme {
@@ -144,63 +388,131 @@ impl Catalog for Theme {
         Box::new(primary)
     }
 
-    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
-        class(self, status)
+    fn style(&self, class: &Self::Class<'_>, args: StyleArgs) -> Style {
+        class(self, args)
     }
 }
 
 /// The default style for a [`TabBar`](crate::TabBar).
+///
+/// All colors are taken from the theme's extended palette, so the tab bar
+/// follows light, dark and custom themes.
+#[must_use]
+pub fn primary(theme: &Theme, args: StyleArgs) -> Style {
+    from_palette(theme.extended_palette(), args)
+}
+
+/// The [`primary`] style built from any extended palette, for themes other
+/// than [`iced::Theme`].
+///
+/// A custom theme can generate one from its base colors with
+/// [`Extended::generate`].
 #[must_use]
-pub fn primary(theme: &Theme, status: Status) -> Style {
+pub fn from_palette(extended: &Extended, args: StyleArgs) -> Style {
+    let status = args.status;
     let mut style = Style::default();
-    let extended = theme.extended_palette();
     let bg = &extended.background;
     let primary = &extended.primary;
+    let danger = &extended.danger;
 
-    style.bar.background = Some(Background::Color(bg.weak.color));
+    style.bar.background = Some(Background::Color(bg.base.color));
     style.bar.border_color = Some(bg.strong.color);
     style.bar.border_width = 1.0;
 
+    style.tab.close_hover_background = Some(Background::Color(danger.base.color));
+
+    style.section.text_color = bg.base.text.scale_alpha(0.6);
+
     style.tooltip.background = Background::Color(bg.strong.color);
     style.tooltip.text_color = bg.strong.text;
     style.tooltip.border_color = bg.stronger.color;
 
     match status {
         Status::Inactive => {
-            style.tab.background = Background::Color(bg.weakest.color);
-            style.tab.text_color = bg.weakest.text;
-            style.tab.icon_color = style.tab.text_color;
-            style.tab.border_color = bg.weak.color;
+            style.tab.background = Background::Color(bg.weak.color);
+            style.tab.text_color = bg.weak.text;
+            style.tab.border_color = bg.strong.color;
             style.tab.border_width = 0.0;
         }
+        Status::Focused => {
+            style.tab.background = Background::Color(bg.weak.color);
+            style.tab.text_color = bg.weak.text;
+            style.tab.border_width = 0.0;
+            style.tab.focus_border_color = Some(primary.base.color);
+        }
         Status::Hovered => {
-            style.tab.background = Background::Color(bg.weaker.color);
-            style.tab.text_color = bg.weaker.text;
-            style.tab.icon_color = style.tab.text_color;
-            style.tab.border_color = bg.weak.color;
+            style.tab.background = Background::Color(mix(bg.weak.color, primary.weak.color, 0.5));
+            style.tab.text_color = primary.weak.text;
+            style.tab.border_color = primary.weak.color;
             style.tab.border_width = 0.0;
         }
         Status::Active | Status::Dragging => {
-            style.tab.background = Background::Color(primary.strong.color);
-            style.tab.text_color = primary.strong.text;
-            style.tab.icon_color = style.tab.text_color;
+            style.tab.background = Background::Color(primary.base.color);
+            style.tab.text_color = primary.base.text;
+            style.tab.border_color = primary.strong.color;
+            style.tab.border_width = 1.0;
+            // Lift the active tab off the bar.
+            style.tab.shadow = Shadow {
+                color: Color::BLACK.scale_alpha(0.25),
+                offset: Vector::new(0.0, 2.0),
+                blur_radius: 4.0,
+            };
+            style.tab.drag_placeholder_background =
+                Some(Background::Color(bg.strong.color.scale_alpha(0.4)));
+        }
+        Status::Pressed => {
+            style.tab.background = Background::Color(mix(primary.base.color, Color::BLACK, 0.15));
+            style.tab.text_color = primary.base.text;
             style.tab.border_color = primary.strong.color;
             style.tab.border_width = 1.0;
         }
     }
+    style.tab.icon_color = style.tab.text_color;
+    if matches!(status, Status::Inactive | Status::Focused | Status::Hovered) {
+        style.tab.filter_match_color = Some(primary.strong.color);
+    }
+
+    style
+}
 
+/// A browser-like variant of [`primary`]: tabs have rounded top corners and
+/// square bottoms, so they sit flat on the bottom of the bar.
+///
+/// [`TabStyle::border_radius`] is drawn per corner, so any [`Radius`] works
+/// the same way in a custom style.
+#[must_use]
+pub fn browser_tabs(theme: &Theme, args: StyleArgs) -> Style {
+    let mut style = primary(theme, args);
+    style.tab.border_radius = Radius::new(0.0).top(8.0);
+    // Tabs are part of the bar rather than lifted off it.
+    style.tab.shadow = Shadow::default();
     style
 }
 
+/// Linearly interpolates between two colors.
+pub(crate) fn mix(a: Color, b: Color, factor: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * factor,
+        g: a.g + (b.g - a.g) * factor,
+        b: a.b + (b.b - a.b) * factor,
+        a: a.a + (b.a - a.a) * factor,
+    }
+}
+
 #[must_use]
-pub fn cool(_theme: &Theme, status: Status) -> Style {
+pub fn cool(_theme: &Theme, args: StyleArgs) -> Style {
     let mut base = Style::default();
 
-    match status {
+    match args.status {
         Status::Inactive => {
             base.tab.background = Background::Color(Color::TRANSPARENT);
             base.tab.border_width = 0.0;
         }
+        Status::Focused => {
+            base.tab.background = Background::Color(Color::TRANSPARENT);
+            base.tab.border_width = 0.0;
+            base.tab.focus_border_color = Some(Color::from_rgb(0.5, 0.6, 0.9));
+        }
         Status::Hovered => {
             base.tab.background = Background::Color(Color::from_rgba(0.7, 0.7, 0.7, 0.2));
             base.tab.border_width = 0.0;
@@ -208,7 +520,25 @@ pub fn cool(_theme: &Theme, status: Status) -> Style {
         Status::Active | Status::Dragging => {
             base.tab.background = Background::Color(Color::from_rgb(0.4, 0.4, 0.4));
         }
+        Status::Pressed => {
+            base.tab.background = Background::Color(Color::from_rgb(0.33, 0.33, 0.33));
+        }
     }
 
     base
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_active_tab_casts_a_shadow() {
+        for theme in [Theme::Light, Theme::Dark] {
+            let active = primary(&theme, Status::Active.into()).tab.shadow;
+            let inactive = primary(&theme, Status::Inactive.into()).tab.shadow;
+            assert_ne!(active, inactive);
+            assert_eq!(inactive, Shadow::default());
+        }
+    }
+}
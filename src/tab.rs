@@ -1,8 +1,12 @@
 //! Content widget for [`TabBar`](super::TabBar) (handles selection/close in content-space for Scrollable).
 
 use crate::Status;
-use crate::style::{Catalog, TooltipStyle};
-use crate::tab_bar::{Position, ensure_child_tree};
+use crate::style::{Catalog, DragOverlayStyle, SectionStyle, Style, StyleArgs, TooltipStyle, mix};
+use crate::tab_bar::{
+    CloseIconSource, ExternalDrag, OverflowBehavior, Position, ReselectBehavior, ScrollEdge,
+    TabDragChannel, TooltipContent, ensure_child_tree,
+};
+#[cfg(feature = "svg")]
 use iced::advanced::svg;
 use iced::advanced::{
     Clipboard, Layout, Overlay, Shell, Widget,
@@ -12,24 +16,57 @@ use iced::advanced::{
 };
 use iced::widget::{Column, Container, Row, Space, Text, container, text};
 use iced::{
-    Alignment, Border, Element, Event, Font, Length, Padding, Pixels, Point, Rectangle, Size,
+    Alignment, Background, Border, Color, Element, Event, Font, Length, Padding, Pixels, Point,
+    Rectangle, Size, Transformation, Vector,
     alignment::{Horizontal, Vertical},
+    keyboard::{self, key},
     mouse, touch,
 };
 use iced_fonts::CODICON_FONT;
+use std::any::Any;
+use std::borrow::Cow;
+use std::cell::Cell;
 use std::fmt;
 use std::marker::PhantomData;
-use std::sync::{Arc, LazyLock};
+use std::rc::Rc;
+#[cfg(feature = "svg")]
+use std::sync::LazyLock;
 use std::time::{Duration, Instant};
 
-/// Offset added to icon/text size during layout to prevent clipping.
-const LAYOUT_SIZE_OFFSET: f32 = 1.0;
-/// Multiplier for close button hit area (larger than icon for easier clicking).
-const CLOSE_HIT_AREA_MULTIPLIER: f32 = 1.3;
+#[cfg(feature = "svg")]
 const CLOSE_SVG: &[u8] = include_bytes!("../assets/close.svg");
 /// Cached SVG handle for the close icon (avoids re-allocating on every draw call).
+#[cfg(feature = "svg")]
 static CLOSE_SVG_HANDLE: LazyLock<svg::Handle> =
     LazyLock::new(|| svg::Handle::from_memory(CLOSE_SVG));
+/// The codicon "close" glyph, drawn in place of the SVG without the `svg` feature.
+#[cfg(not(feature = "svg"))]
+const CODICON_CLOSE: char = '\u{ea76}';
+
+/// Renderer support needed to draw the close icon.
+///
+/// With the `svg` feature (on by default) this requires an
+/// [`svg::Renderer`](iced::advanced::svg::Renderer), since
+/// [`CloseIconSource::Svg`] draws an embedded SVG. Without the feature every
+/// renderer implements it and the close icon is always drawn from a font.
+#[cfg(feature = "svg")]
+pub trait CloseIconRenderer: svg::Renderer {}
+
+#[cfg(feature = "svg")]
+impl<T: svg::Renderer> CloseIconRenderer for T {}
+
+/// Renderer support needed to draw the close icon.
+///
+/// With the `svg` feature (on by default) this requires an
+/// `svg::Renderer`, since [`CloseIconSource::Svg`] draws an embedded SVG.
+/// Without the feature every renderer implements it and the close icon is
+/// always drawn from a font.
+#[cfg(not(feature = "svg"))]
+pub trait CloseIconRenderer {}
+
+#[cfg(not(feature = "svg"))]
+impl<T> CloseIconRenderer for T {}
+
 /// The content label displayed on a tab in the [`TabBar`](super::TabBar).
 #[derive(Clone, Hash, Debug)]
 pub enum TabLabel {
@@ -74,11 +111,42 @@ impl From<(char, String)> for TabLabel {
     }
 }
 
+/// Maximum time between two clicks for them to count as a double-click.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(300);
+/// Maximum distance (in pixels) between two clicks of a double-click.
+const DOUBLE_CLICK_DISTANCE: f32 = 4.0;
+
+/// Distance beyond the border width within which a click counts as an edge click.
+const EDGE_CLICK_SLOP: f32 = 3.0;
+
+/// How long the strip must be left alone after scrolling before it snaps to a
+/// tab edge.
+const SCROLL_SNAP_DELAY: Duration = Duration::from_millis(150);
+
+/// How far the strip must scroll away from an end before reaching it again
+/// is reported through `on_scroll_edge` once more.
+const SCROLL_EDGE_HYSTERESIS: f32 = 16.0;
+
+/// Width of the focus ring drawn around a tab with keyboard focus.
+const FOCUS_RING_WIDTH: f32 = 2.0;
+
+/// Opacity of tabs that don't match the filter text.
+const FILTER_DIM_ALPHA: f32 = 0.35;
+
+/// Status used for tabs that have not been resolved by `Tab::update` yet.
+const NO_STATUS: (Option<Status>, Option<bool>) = (None, None);
+
+/// Status of a dragged tab drawn in the row, without a drag overlay.
+const DRAGGING_STATUS: (Option<Status>, Option<bool>) = (Some(Status::Dragging), None);
+
 /// Tracks the state of an in-progress tab drag operation.
 #[derive(Debug, Clone)]
 pub struct DragState {
     /// Index of the tab being dragged.
     pub tab_index: usize,
+    /// Id of the tab being dragged, used to follow it to its new index if the
+    /// application adds or removes tabs during the drag.
+    pub tab_id: Rc<dyn Any>,
     /// Mouse position when the press occurred.
     pub press_origin: Point,
     /// Current mouse position (updated on every move event).
@@ -94,6 +162,151 @@ pub struct DragState {
     /// Cursor position in window coordinates (updated at the TabBar level
     /// so it stays current even when the cursor leaves the Scrollable).
     pub overlay_pos: Point,
+    /// When the press occurred.
+    pub press_time: Instant,
+    /// Whether the press came from a touch (as opposed to a mouse).
+    pub is_touch: bool,
+    /// Drop index last reported to `on_reorder_preview`.
+    pub preview_target: usize,
+}
+
+/// Returns the tooltip of a tab: its explicit tooltip or, when `auto` is set,
+/// its name or text label.
+pub(crate) fn tooltip_text<'b>(
+    tooltip: Option<&'b String>,
+    name: Option<&'b String>,
+    label: &'b TabLabel,
+    auto: bool,
+) -> Option<&'b str> {
+    if let Some(tooltip) = tooltip {
+        return Some(tooltip);
+    }
+    if !auto {
+        return None;
+    }
+    name.map(String::as_str).or(match label {
+        TabLabel::Text(text) | TabLabel::IconText(_, text) => Some(text),
+        TabLabel::Icon(_) => None,
+    })
+}
+
+/// A tab label being edited in place.
+#[derive(Debug, Clone)]
+pub struct Rename {
+    /// Index of the tab being renamed.
+    pub index: usize,
+    /// The label text before editing started.
+    pub original: String,
+    /// The edited text.
+    pub value: String,
+    /// Byte offset of the caret in `value`.
+    pub cursor: usize,
+}
+
+/// A tab whose close button was clicked, kept around to animate it out.
+#[derive(Debug, Clone)]
+pub struct ClosingTab {
+    /// Index of the closed tab.
+    pub index: usize,
+    /// Id of the closed tab, to tell when the application has removed it.
+    pub id: Rc<dyn Any>,
+    /// Label of the closed tab.
+    pub label: TabLabel,
+    /// Status of the closed tab when the close button was clicked.
+    pub status: (Option<Status>, Option<bool>),
+    /// Background override of the closed tab.
+    pub background: Option<Background>,
+    /// Close icon color override of the closed tab.
+    pub close_color: Option<Color>,
+    /// Layout of the closed tab, relative to the row.
+    pub node: Node,
+    /// When the close button was clicked.
+    pub requested: Instant,
+    /// When the tab disappeared from the bar, starting the animation.
+    pub removed: Option<Instant>,
+}
+
+/// Cross-fade of a tab between its inactive and hovered backgrounds.
+#[derive(Debug, Clone, Copy)]
+pub struct HoverFade {
+    /// Whether the fade runs towards the hovered style.
+    pub hovered: bool,
+    /// Progress when the fade started.
+    pub from: f32,
+    /// When the fade started.
+    pub start: Instant,
+}
+
+impl HoverFade {
+    /// Returns how far the tab is faded towards its hovered style, from `0.0`
+    /// to `1.0`.
+    pub fn progress(&self, duration: Duration) -> f32 {
+        let step = self.start.elapsed().as_secs_f32() / duration.as_secs_f32();
+        if self.hovered {
+            (self.from + step).min(1.0)
+        } else {
+            (self.from - step).max(0.0)
+        }
+    }
+}
+
+impl Default for HoverFade {
+    fn default() -> Self {
+        Self {
+            hovered: false,
+            from: 0.0,
+            start: Instant::now(),
+        }
+    }
+}
+
+/// The active tab indicator sliding from where it was drawn to a newly
+/// selected tab.
+#[derive(Debug, Clone, Copy)]
+pub struct IndicatorSlide {
+    /// Bounds of the indicator when the slide started.
+    pub from: Rectangle,
+    /// When the slide started.
+    pub start: Instant,
+}
+
+impl IndicatorSlide {
+    /// Returns how far the indicator has moved towards the active tab, from
+    /// `0.0` to `1.0`.
+    pub fn progress(&self, duration: Duration) -> f32 {
+        (self.start.elapsed().as_secs_f32() / duration.as_secs_f32()).min(1.0)
+    }
+}
+
+/// The last click on the tab strip, used to detect double-clicks.
+#[derive(Debug, Clone, Copy)]
+pub struct LastClick {
+    /// When the click occurred.
+    pub time: Instant,
+    /// Where the click occurred.
+    pub position: Point,
+    /// Index of the clicked tab, or `None` for the empty strip area.
+    pub tab_index: Option<usize>,
+}
+
+/// Schedules a redraw for the moment a tooltip hovered since `hover_start`
+/// is due, so it shows up even if the cursor stays still.
+///
+/// A delay too large to add to `hover_start` never elapses, so nothing is
+/// scheduled for it.
+fn request_tooltip_redraw<Message>(
+    shell: &mut Shell<'_, Message>,
+    hover_start: Instant,
+    delay: Duration,
+) {
+    if delay.is_zero() {
+        return;
+    }
+    if let Some(due) = hover_start.checked_add(delay)
+        && due > Instant::now()
+    {
+        shell.request_redraw_at(due);
+    }
 }
 
 /// Tracks hover timing for a tab tooltip.
@@ -105,6 +318,10 @@ pub struct TooltipState {
     pub hover_start: Instant,
     /// Last-known cursor position (in window coordinates).
     pub cursor_pos: Point,
+    /// Whether the hover is over the tab's close button rather than its body.
+    pub is_close: bool,
+    /// Bounds of the hovered tab, in the same coordinates as `cursor_pos`.
+    pub tab_bounds: Rectangle,
 }
 
 /// State stored in `TabBarContent`'s tree for persisting `tab_statuses`.
@@ -115,6 +332,105 @@ pub struct TabBarContentState {
     pub drag: Option<DragState>,
     /// Active tooltip hover tracking, if any.
     pub tooltip: Option<TooltipState>,
+    /// Tooltip style resolved from the theme during the last draw.
+    ///
+    /// Overlays are laid out without access to the theme, so the style is
+    /// cached here for [`TooltipOverlay`] to pick up.
+    pub tooltip_style: Cell<TooltipStyle>,
+    /// Border width of the active tab style, resolved during the last draw
+    /// for edge-click hit testing.
+    pub edge_width: Cell<f32>,
+    /// Bounds of the active tab indicator from the last draw.
+    pub indicator_bounds: Cell<Option<Rectangle>>,
+    /// Visible bounds of the active tab from the last draw, in window
+    /// coordinates, where the bar's baseline is broken.
+    pub active_bounds: Cell<Option<Rectangle>>,
+    /// The tab the indicator marks, as last seen by `update`.
+    pub indicator_tab: Option<usize>,
+    /// The indicator sliding to a newly selected tab, if any.
+    pub indicator_slide: Option<IndicatorSlide>,
+    /// The tab being animated out after its close button was clicked.
+    pub closing: Option<ClosingTab>,
+    /// The tab whose label is being edited in place.
+    pub renaming: Option<Rename>,
+    /// The last click on the strip, if it may still become a double-click.
+    pub last_click: Option<LastClick>,
+    /// Index of the tab the mouse button or finger is held down on, if any.
+    pub pressed: Option<usize>,
+    /// Index of the tab to select when the press on it is released, with
+    /// `select_on_release`. Cleared once the press turns into a drag.
+    pub pending_select: Option<usize>,
+    /// Index of the tab that has keyboard focus, if any.
+    ///
+    /// A tab gains focus when it is clicked and loses it when a click lands
+    /// outside the tab bar.
+    pub focused: Option<usize>,
+    /// Set by the [`TabBar`](super::TabBar) when the event being processed
+    /// scrolls the strip.
+    pub just_scrolled: bool,
+    /// When the strip was last scrolled, set by the [`TabBar`](super::TabBar)
+    /// while scroll snapping is enabled.
+    pub snap_after: Option<Instant>,
+    /// Scroll distance that settles the strip on a tab edge, taken by the
+    /// [`TabBar`](super::TabBar) to scroll the `Scrollable`.
+    pub snap_delta: Option<f32>,
+    /// When the strip was last scrolled, set by the [`TabBar`](super::TabBar)
+    /// to keep a [`ScrollMode::FloatingAutoHide`](crate::ScrollMode) scrollbar
+    /// visible for a moment.
+    pub scrolled_at: Option<Instant>,
+    /// Whether the cursor was over the bar on the last event, tracked by the
+    /// [`TabBar`](super::TabBar) to redraw an auto-hiding scrollbar.
+    pub bar_hovered: bool,
+    /// The finger panning the strip and where it last scrolled it to,
+    /// tracked by the [`TabBar`](super::TabBar) for touch scrolling.
+    pub touch_scroll: Option<(touch::Finger, Point)>,
+    /// Horizontal offset of the tabs within the row, set by the
+    /// [`TabBar`](super::TabBar) to align tabs that don't fill the bar.
+    pub align_offset: f32,
+    /// Where a tab dragged in from another bar would be inserted.
+    pub external_drop: Option<usize>,
+    /// Width the tabs should be collapsed to fit into, set by the
+    /// [`TabBar`](super::TabBar) when labels collapse on overflow.
+    pub fit_width: Option<f32>,
+    /// When the labels are collapsed to fit, the maximum width of text labels
+    /// (infinite when only icons are collapsed).
+    pub collapsed: Option<f32>,
+    /// Height the tab labels were last laid out with. With `auto_height` it
+    /// follows the tallest label, so it can differ from the configured one.
+    pub label_height: Option<Length>,
+    /// The keyboard modifiers currently held down.
+    pub modifiers: keyboard::Modifiers,
+    /// Hover cross-fade of each tab, used when a hover animation is set.
+    pub hover_fades: Vec<HoverFade>,
+    /// Id and window-space bounds of every tab as last reported through
+    /// `on_tab_bounds`, so a tab is reported again when either changes.
+    pub reported_bounds: Vec<(Rc<dyn Any>, Rectangle)>,
+    /// Whether the tabs overflowed the scrollable as last reported through
+    /// `on_overflow`.
+    pub reported_overflow: Option<bool>,
+    /// The end of the strip last reported through `on_scroll_edge`, until the
+    /// strip scrolls far enough away from it.
+    pub reported_edge: Option<ScrollEdge>,
+    /// Measured size of the tooltip text from the last time a tooltip was
+    /// laid out.
+    pub tooltip_measurement: Option<TooltipMeasurement>,
+}
+
+/// The measured size of a tooltip's text along with the inputs it was
+/// measured with.
+///
+/// [`TooltipOverlay`] is recreated every frame, so this lives in
+/// [`TabBarContentState`] to let re-layout skip shaping the text while the
+/// same tooltip stays up. Only the text box is cached: the overlay is still
+/// positioned against the current cursor, so moving the cursor never
+/// invalidates it.
+#[derive(Debug, Clone)]
+pub struct TooltipMeasurement {
+    pub text: String,
+    pub text_size: f32,
+    pub font: Font,
+    pub max_width: f32,
+    pub size: Size,
 }
 
 pub struct Tab<'a, 'b, Message, TabId, Theme = iced::Theme, Renderer = iced::Renderer>
@@ -126,10 +442,24 @@ where
     tab_labels: &'a [TabLabel],
     tab_statuses: &'a [(Option<Status>, Option<bool>)],
     tab_indices: &'a [TabId],
-    tab_tooltips: &'a [Option<String>],
+    tab_tooltips: &'a [Option<TooltipContent<'b, Message, Theme, Renderer>>],
+    /// Names given to tabs through `push_with_name`.
+    tab_names: &'a [Option<String>],
+    /// Per-tab background overrides.
+    tab_backgrounds: &'a [Option<Background>],
+    tab_close_colors: &'a [Option<Color>],
+    /// Whether each tab is locked in place.
+    tab_locked: &'a [bool],
+    sections: &'a [(usize, String)],
+    /// Text that tab labels are matched against, if any.
+    filter: Option<&'a str>,
+    has_close_tooltip: bool,
+    auto_tooltip: bool,
     icon_size: f32,
     text_size: f32,
     close_size: f32,
+    close_hit_area: f32,
+    layout_size_offset: f32,
     close_spacing: f32,
     icon_spacing: f32,
     padding: Padding,
@@ -137,14 +467,59 @@ where
     font: Option<Font>,
     text_font: Option<Font>,
     height: Length,
+    auto_height: bool,
     position: Position,
     tab_width: Option<f32>,
-    drag_threshold: f32,
+    reorder_threshold: f32,
+    detach_threshold: f32,
+    touch_reorder_delay: Duration,
+    select_on_release: bool,
+    reselect_behavior: &'a ReselectBehavior<Message, TabId>,
+    drag_cursor: mouse::Interaction,
+    drag_overlay: bool,
+    close_animation: Duration,
+    hover_animation: Duration,
+    indicator_animation: Duration,
+    overflow_behavior: OverflowBehavior,
+    close_icon: &'a CloseIconSource,
     has_close: bool,
-    on_select: Arc<dyn Fn(TabId) -> Message>,
-    on_close: Option<Arc<dyn Fn(TabId) -> Message>>,
-    on_reorder: Option<Arc<dyn Fn(usize, usize) -> Message>>,
-    active_tab: usize,
+    /// Whether keyboard shortcuts close the focused tab.
+    keyboard_close: bool,
+    /// Whether bare Delete and Backspace are among those shortcuts.
+    close_on_delete: bool,
+    /// Whether closing the active tab also selects its neighbor.
+    select_neighbor_on_close: bool,
+    on_select: &'a dyn Fn(usize, TabId) -> Message,
+    on_close: Option<&'a dyn Fn(usize, TabId) -> Message>,
+    on_reorder: Option<&'a dyn Fn(usize, usize) -> Message>,
+    on_reorder_ids: Option<&'a dyn Fn(Vec<TabId>) -> Message>,
+    on_move: Option<&'a dyn Fn(TabId, usize) -> Message>,
+    on_tab_bounds: Option<&'a dyn Fn(TabId, Rectangle) -> Message>,
+    on_overflow: Option<&'a dyn Fn(bool) -> Message>,
+    on_scroll_edge: Option<&'a dyn Fn(ScrollEdge) -> Message>,
+    on_double_click: Option<&'a dyn Fn(Option<TabId>) -> Message>,
+    on_new_tab: Option<&'a dyn Fn() -> Message>,
+    on_drag_start: Option<&'a dyn Fn(TabId) -> Message>,
+    on_drag_end: Option<&'a dyn Fn() -> Message>,
+    on_drag_out: Option<&'a dyn Fn(TabId) -> Message>,
+    on_accept_external: Option<&'a dyn Fn(TabId, usize) -> Message>,
+    on_reorder_preview: Option<&'a dyn Fn(usize, usize) -> Message>,
+    on_rename: Option<&'a dyn Fn(TabId, String) -> Message>,
+    on_close_others: Option<&'a dyn Fn(TabId) -> Message>,
+    on_edge_click: Option<&'a dyn Fn(TabId) -> Message>,
+    on_empty: Option<&'a dyn Fn() -> Message>,
+    drag_channel: Option<&'a TabDragChannel<TabId>>,
+    /// Index of the active tab in this row, as shown.
+    active_tab: Option<usize>,
+    /// Whether this row sits inside the bar's `Scrollable`.
+    scrollable: bool,
+    /// Index of the first tab of this row within the whole bar.
+    first_index: usize,
+    /// Whether the row shows the tabs in reverse of the application's order,
+    /// which the slices above keep.
+    reversed: bool,
+    /// Number of tabs in the whole bar.
+    total: usize,
     tooltip_delay: Duration,
     class: &'a <Theme as Catalog>::Class<'b>,
     _renderer: PhantomData<Renderer>,
@@ -172,17 +547,27 @@ impl<'a, 'b, Message, TabId, Theme, Renderer> Tab<'a, 'b, Message, TabId, Theme,
 where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
     Theme: Catalog + text::Catalog + container::Catalog,
-    TabId: Eq + Clone,
+    TabId: 'static + Eq + Clone,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         tab_labels: &'a [TabLabel],
         tab_statuses: &'a [(Option<Status>, Option<bool>)],
         tab_indices: &'a [TabId],
-        tab_tooltips: &'a [Option<String>],
+        tab_tooltips: &'a [Option<TooltipContent<'b, Message, Theme, Renderer>>],
+        tab_names: &'a [Option<String>],
+        tab_backgrounds: &'a [Option<Background>],
+        tab_close_colors: &'a [Option<Color>],
+        tab_locked: &'a [bool],
+        sections: &'a [(usize, String)],
+        filter: Option<&'a str>,
+        has_close_tooltip: bool,
+        auto_tooltip: bool,
         icon_size: f32,
         text_size: f32,
         close_size: f32,
+        close_hit_area: f32,
+        layout_size_offset: f32,
         close_spacing: f32,
         icon_spacing: f32,
         padding: Padding,
@@ -190,17 +575,64 @@ where
         font: Option<Font>,
         text_font: Option<Font>,
         height: Length,
+        auto_height: bool,
         position: Position,
         tab_width: Option<f32>,
-        drag_threshold: f32,
+        reorder_threshold: f32,
+        detach_threshold: f32,
+        touch_reorder_delay: Duration,
+        select_on_release: bool,
+        reselect_behavior: &'a ReselectBehavior<Message, TabId>,
+        drag_cursor: mouse::Interaction,
+        drag_overlay: bool,
+        close_animation: Duration,
+        hover_animation: Duration,
+        indicator_animation: Duration,
+        overflow_behavior: OverflowBehavior,
+        close_icon: &'a CloseIconSource,
         has_close: bool,
-        active_tab: usize,
-        on_select: Arc<dyn Fn(TabId) -> Message>,
-        on_close: Option<Arc<dyn Fn(TabId) -> Message>>,
-        on_reorder: Option<Arc<dyn Fn(usize, usize) -> Message>>,
+        keyboard_close: bool,
+        close_on_delete: bool,
+        select_neighbor_on_close: bool,
+        active_tab: Option<usize>,
+        scrollable: bool,
+        first_index: usize,
+        reversed: bool,
+        total: usize,
+        on_select: &'a dyn Fn(usize, TabId) -> Message,
+        on_close: Option<&'a dyn Fn(usize, TabId) -> Message>,
+        on_reorder: Option<&'a dyn Fn(usize, usize) -> Message>,
+        on_reorder_ids: Option<&'a dyn Fn(Vec<TabId>) -> Message>,
+        on_move: Option<&'a dyn Fn(TabId, usize) -> Message>,
+        on_tab_bounds: Option<&'a dyn Fn(TabId, Rectangle) -> Message>,
+        on_overflow: Option<&'a dyn Fn(bool) -> Message>,
+        on_scroll_edge: Option<&'a dyn Fn(ScrollEdge) -> Message>,
+        on_double_click: Option<&'a dyn Fn(Option<TabId>) -> Message>,
+        on_new_tab: Option<&'a dyn Fn() -> Message>,
+        on_drag_start: Option<&'a dyn Fn(TabId) -> Message>,
+        on_drag_end: Option<&'a dyn Fn() -> Message>,
+        on_drag_out: Option<&'a dyn Fn(TabId) -> Message>,
+        on_accept_external: Option<&'a dyn Fn(TabId, usize) -> Message>,
+        on_reorder_preview: Option<&'a dyn Fn(usize, usize) -> Message>,
+        on_rename: Option<&'a dyn Fn(TabId, String) -> Message>,
+        on_close_others: Option<&'a dyn Fn(TabId) -> Message>,
+        on_edge_click: Option<&'a dyn Fn(TabId) -> Message>,
+        on_empty: Option<&'a dyn Fn() -> Message>,
+        drag_channel: Option<&'a TabDragChannel<TabId>>,
         tooltip_delay: Duration,
         class: &'a <Theme as Catalog>::Class<'b>,
     ) -> Self {
+        // The active tab is given in the application's order; the row keeps
+        // it at the position it is shown at.
+        let active_tab = active_tab
+            .filter(|&active_tab| active_tab < tab_indices.len())
+            .map(|active_tab| {
+                if reversed {
+                    tab_indices.len() - 1 - active_tab
+                } else {
+                    active_tab
+                }
+            });
         Self {
             tab_labels,
             tab_statuses,
@@ -208,6 +640,8 @@ where
             icon_size,
             text_size,
             close_size,
+            close_hit_area,
+            layout_size_offset,
             close_spacing,
             icon_spacing,
             padding,
@@ -215,47 +649,630 @@ where
             font,
             text_font,
             height,
+            auto_height,
             position,
             tab_width,
-            drag_threshold,
+            reorder_threshold,
+            detach_threshold,
+            touch_reorder_delay,
+            select_on_release,
+            reselect_behavior,
+            drag_cursor,
+            drag_overlay,
+            close_animation,
+            hover_animation,
+            indicator_animation,
+            overflow_behavior,
+            close_icon,
             has_close,
+            keyboard_close,
+            close_on_delete,
+            select_neighbor_on_close,
             on_select,
             on_close,
             on_reorder,
+            on_reorder_ids,
+            on_move,
+            on_tab_bounds,
+            on_overflow,
+            on_scroll_edge,
+            on_double_click,
+            on_new_tab,
+            on_drag_start,
+            on_drag_end,
+            on_drag_out,
+            on_accept_external,
+            on_reorder_preview,
+            on_rename,
+            on_close_others,
+            on_edge_click,
+            on_empty,
+            drag_channel,
             active_tab,
+            scrollable,
+            first_index,
+            reversed,
+            total,
             tab_tooltips,
+            tab_names,
+            tab_backgrounds,
+            tab_close_colors,
+            tab_locked,
+            sections,
+            filter,
+            has_close_tooltip,
+            auto_tooltip,
             tooltip_delay,
             class,
             _renderer: PhantomData,
         }
     }
 
-    fn row_element(&self) -> Row<'_, Message, Theme, Renderer> {
-        self.tab_labels
-            .iter()
-            .fold(Row::<Message, Theme, Renderer>::new(), |row, tab_label| {
-                let label_row = build_single_tab_row::<Message, Theme, Renderer>(
-                    tab_label,
-                    self.icon_size,
-                    self.text_size,
-                    self.close_size,
-                    self.close_spacing,
-                    self.icon_spacing,
-                    self.padding,
-                    self.tab_width,
-                    self.height,
-                    self.has_close,
-                    self.position,
-                    self.font,
-                    self.text_font,
-                );
-                row.push(label_row)
-            })
+    fn row_element(
+        &self,
+        collapsed: Option<f32>,
+        label_height: Length,
+    ) -> Row<'_, Message, Theme, Renderer> {
+        self.row_entries()
+            .into_iter()
+            .fold(
+                Row::<Message, Theme, Renderer>::new(),
+                |row, entry| match entry {
+                    RowEntry::Tab(i) => row.push(build_single_tab_row::<Message, Theme, Renderer>(
+                        &self.tab_labels[self.stored_index(i)],
+                        self.icon_size,
+                        self.text_size,
+                        self.close_size,
+                        self.close_hit_area,
+                        self.layout_size_offset,
+                        self.close_spacing,
+                        self.icon_spacing,
+                        self.padding,
+                        self.tab_width,
+                        label_height,
+                        self.has_close,
+                        self.position,
+                        self.font,
+                        self.text_font,
+                        self.tab_collapse(i, collapsed),
+                    )),
+                    RowEntry::Section(label) => row.push(
+                        Container::new(
+                            Text::new(label)
+                                .size(self.text_size)
+                                .font(self.text_font.unwrap_or_default()),
+                        )
+                        .padding(self.padding)
+                        .height(self.row_height())
+                        .align_y(Vertical::Center),
+                    ),
+                },
+            )
             .width(Length::Shrink)
-            .height(self.height)
+            .height(self.row_height())
             .spacing(self.spacing)
             .align_y(Alignment::Center)
     }
+
+    /// Returns the height of the row, which follows its tallest tab when
+    /// `auto_height` is set.
+    fn row_height(&self) -> Length {
+        if self.auto_height {
+            Length::Shrink
+        } else {
+            self.height
+        }
+    }
+
+    /// Returns the height the labels were last laid out with, or the
+    /// configured one before the first layout.
+    fn label_height(&self, content_state: &TabBarContentState) -> Length {
+        content_state.label_height.unwrap_or(self.height)
+    }
+
+    /// Returns the height of the tallest tab label in a row laid out with
+    /// shrinking labels.
+    fn tallest_label(&self, node: &Node) -> f32 {
+        self.row_entries()
+            .into_iter()
+            .zip(node.children())
+            .filter_map(|(entry, child)| match entry {
+                RowEntry::Tab(_) => child.children().first().map(|label| label.size().height),
+                RowEntry::Section(_) => None,
+            })
+            .fold(0.0, f32::max)
+    }
+
+    /// Returns how the tab at `index` is collapsed, given how the row is.
+    fn tab_collapse(&self, index: usize, collapsed: Option<f32>) -> Option<f32> {
+        let keep_text = matches!(
+            self.overflow_behavior,
+            OverflowBehavior::CollapseToIcons {
+                keep_active_text: true
+            }
+        ) && self.active_tab == Some(index);
+        collapsed.filter(|_| !keep_text)
+    }
+
+    /// Returns the label the tab at `index` is drawn with, which is only its
+    /// icon when it is collapsed.
+    fn display_label(&self, content_state: &TabBarContentState, index: usize) -> Cow<'_, TabLabel> {
+        match &self.tab_labels[self.stored_index(index)] {
+            TabLabel::IconText(icon, _)
+                if self.tab_collapse(index, content_state.collapsed).is_some() =>
+            {
+                Cow::Owned(TabLabel::Icon(*icon))
+            }
+            label => Cow::Borrowed(label),
+        }
+    }
+
+    /// Returns the widest text labels may be for the row laid out as `node` to
+    /// shrink by `excess`, or `None` if it has no text labels.
+    ///
+    /// The widest labels are shortened first, down to a common width.
+    fn text_cap(&self, node: &Node, excess: f32) -> Option<f32> {
+        let mut widths: Vec<f32> = self
+            .row_entries()
+            .into_iter()
+            .zip(node.children())
+            .filter_map(|(entry, child)| match entry {
+                RowEntry::Tab(i)
+                    if matches!(self.tab_labels[self.stored_index(i)], TabLabel::Text(_))
+                        && self.tab_collapse(i, Some(f32::INFINITY)).is_some() =>
+                {
+                    child.children().first().map(|label| label.size().width)
+                }
+                _ => None,
+            })
+            .collect();
+        if widths.is_empty() {
+            return None;
+        }
+        widths.sort_by(|a, b| b.total_cmp(a));
+
+        let mut sum = 0.0;
+        for (k, width) in widths.iter().enumerate() {
+            sum += width;
+            let cap = (sum - excess) / (k + 1) as f32;
+            if cap >= widths.get(k + 1).copied().unwrap_or(0.0) {
+                return Some(cap.max(self.text_size));
+            }
+        }
+        Some(self.text_size)
+    }
+
+    /// Lists the entries of the row in layout order: each section header
+    /// comes right before the tab it was pushed in front of.
+    fn row_entries(&self) -> Vec<RowEntry<'a>> {
+        let mut entries = Vec::with_capacity(self.tab_labels.len() + self.sections.len());
+        let mut sections = self.row_sections().into_iter().peekable();
+        for i in 0..=self.tab_labels.len() {
+            while let Some((_, label)) = sections.next_if(|(position, _)| *position <= i) {
+                entries.push(RowEntry::Section(label));
+            }
+            if i < self.tab_labels.len() {
+                entries.push(RowEntry::Tab(i));
+            }
+        }
+        entries
+    }
+
+    /// Lists the section headers in row order, each with the index of the tab
+    /// it is shown in front of. Reversed, a header pushed in front of tab `p`
+    /// comes after it instead, i.e. in front of the tab shown at `len - p`.
+    fn row_sections(&self) -> Vec<(usize, &'a str)> {
+        let len = self.tab_labels.len();
+        if self.reversed {
+            self.sections
+                .iter()
+                .rev()
+                .map(|(position, label)| (len.saturating_sub(*position), label.as_str()))
+                .collect()
+        } else {
+            self.sections
+                .iter()
+                .map(|(position, label)| (*position, label.as_str()))
+                .collect()
+        }
+    }
+
+    /// Returns the layouts of the tabs within the row's `layout`.
+    pub(crate) fn tab_layouts<'l>(&self, layout: Layout<'l>) -> Vec<Layout<'l>> {
+        self.split_layouts(layout).0
+    }
+
+    /// Splits the row's child layouts into those of the tabs and those of the
+    /// section headers.
+    fn split_layouts<'l>(&self, layout: Layout<'l>) -> (Vec<Layout<'l>>, Vec<Layout<'l>>) {
+        if self.sections.is_empty() {
+            return (layout.children().collect(), Vec::new());
+        }
+
+        let mut tab_layouts = Vec::with_capacity(self.tab_labels.len());
+        let mut section_layouts = Vec::with_capacity(self.sections.len());
+        for (entry, child) in self.row_entries().into_iter().zip(layout.children()) {
+            match entry {
+                RowEntry::Tab(_) => tab_layouts.push(child),
+                RowEntry::Section(_) => section_layouts.push(child),
+            }
+        }
+        (tab_layouts, section_layouts)
+    }
+
+    /// Where the tab at `dragged_index` lands when dropped at `cursor_x`,
+    /// kept within its section.
+    ///
+    /// With fewer than two tabs there is nowhere to move to, so the tab stays
+    /// where it is, even if `dragged_index` is stale.
+    fn drop_index(&self, tab_layouts: &[Layout<'_>], cursor_x: f32, dragged_index: usize) -> usize {
+        if tab_layouts.len() < 2 {
+            return dragged_index;
+        }
+        let count = tab_layouts.len();
+        let group = reorder_range(
+            self.sections,
+            self.tab_locked,
+            self.stored_index(dragged_index),
+            count,
+        );
+        let group = if self.reversed {
+            count - group.end..count - group.start
+        } else {
+            group
+        };
+        compute_drop_index(tab_layouts, cursor_x)
+            .clamp(group.start, group.end.saturating_sub(1).max(group.start))
+    }
+
+    /// Returns the current index of the dragged tab, which shifts when the
+    /// application adds or removes tabs in front of it during the drag, or
+    /// `None` if the tab is gone.
+    fn dragged_index(&self, drag: &DragState) -> Option<usize> {
+        let id = drag.tab_id.downcast_ref::<TabId>()?;
+        if drag.tab_index < self.tab_indices.len() && self.tab_id(drag.tab_index) == id {
+            return Some(drag.tab_index);
+        }
+        let index = self.tab_indices.iter().position(|tab_id| tab_id == id)?;
+        Some(self.stored_index(index))
+    }
+
+    /// Handles an event while a tab label is being edited in place. Returns
+    /// whether the event was consumed by the editor.
+    fn update_rename(
+        &self,
+        content_state: &mut TabBarContentState,
+        event: &Event,
+        cursor: mouse::Cursor,
+        strip: Rectangle,
+        tab_layouts: &[Layout<'_>],
+        shell: &mut Shell<'_, Message>,
+    ) -> bool {
+        let Some(rename) = content_state.renaming.as_mut() else {
+            return false;
+        };
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) => {
+                let previous = |value: &str, cursor: usize| {
+                    value[..cursor]
+                        .char_indices()
+                        .next_back()
+                        .map_or(0, |(i, _)| i)
+                };
+                let next = |value: &str, cursor: usize| {
+                    value[cursor..]
+                        .chars()
+                        .next()
+                        .map_or(cursor, |c| cursor + c.len_utf8())
+                };
+
+                match key {
+                    keyboard::Key::Named(key::Named::Enter) => {
+                        self.commit_rename(content_state, shell);
+                    }
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        content_state.renaming = None;
+                    }
+                    keyboard::Key::Named(key::Named::Backspace) => {
+                        let start = previous(&rename.value, rename.cursor);
+                        rename.value.replace_range(start..rename.cursor, "");
+                        rename.cursor = start;
+                    }
+                    keyboard::Key::Named(key::Named::Delete) => {
+                        let end = next(&rename.value, rename.cursor);
+                        rename.value.replace_range(rename.cursor..end, "");
+                    }
+                    keyboard::Key::Named(key::Named::ArrowLeft) => {
+                        rename.cursor = previous(&rename.value, rename.cursor);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        rename.cursor = next(&rename.value, rename.cursor);
+                    }
+                    keyboard::Key::Named(key::Named::Home) => rename.cursor = 0,
+                    keyboard::Key::Named(key::Named::End) => rename.cursor = rename.value.len(),
+                    _ => {
+                        if let Some(text) = text
+                            && !modifiers.command()
+                            && !modifiers.alt()
+                        {
+                            let text: String = text.chars().filter(|c| !c.is_control()).collect();
+                            rename.value.insert_str(rename.cursor, &text);
+                            rename.cursor += text.len();
+                        }
+                    }
+                }
+                shell.capture_event();
+                shell.request_redraw();
+                true
+            }
+
+            // Clicking elsewhere commits. Clicks on the strip only end the
+            // edit; clicks outside of it go on to whatever is there.
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(pos) = cursor.position() else {
+                    self.commit_rename(content_state, shell);
+                    return false;
+                };
+                let on_edited_tab = tab_layouts
+                    .get(rename.index)
+                    .is_some_and(|tl| tl.bounds().contains(pos));
+                if !on_edited_tab {
+                    self.commit_rename(content_state, shell);
+                }
+                if on_edited_tab || strip.contains(pos) {
+                    shell.capture_event();
+                    shell.request_redraw();
+                    true
+                } else {
+                    false
+                }
+            }
+
+            _ => false,
+        }
+    }
+
+    /// Publishes the selection of the tab at `index`, or what the
+    /// `reselect_behavior` asks for if it is already active.
+    fn select(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        let id = self.tab_id(index).clone();
+        if self.active_tab == Some(index) {
+            match self.reselect_behavior {
+                ReselectBehavior::Emit => {}
+                ReselectBehavior::Suppress => return,
+                ReselectBehavior::EmitReselect(on_reselect) => {
+                    shell.publish(on_reselect(id));
+                    return;
+                }
+            }
+        }
+        shell.publish((self.on_select)(self.model_index(index), id));
+    }
+
+    /// Maps the index of a tab in this row to its index in the tab slices,
+    /// which are in the application's order.
+    fn stored_index(&self, index: usize) -> usize {
+        if self.reversed {
+            self.tab_indices.len() - 1 - index
+        } else {
+            index
+        }
+    }
+
+    /// Returns the id of the tab at `index` of this row.
+    fn tab_id(&self, index: usize) -> &TabId {
+        &self.tab_indices[self.stored_index(index)]
+    }
+
+    /// Maps the index of a tab in this row to its index in the application's
+    /// order.
+    fn model_index(&self, index: usize) -> usize {
+        self.first_index + self.stored_index(index)
+    }
+
+    /// Maps an insertion point in this row (`0..=len`) to the application's
+    /// order.
+    fn model_insert_index(&self, index: usize) -> usize {
+        if self.reversed {
+            self.first_index + self.tab_indices.len() - index
+        } else {
+            self.first_index + index
+        }
+    }
+
+    /// Publishes `on_close` for the tab at `index` of the row laid out as
+    /// `layout`, selecting its neighbor and starting the close animation as
+    /// configured.
+    fn close_tab(
+        &self,
+        content_state: &mut TabBarContentState,
+        layout: Layout<'_>,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(on_close) = self.on_close else {
+            return;
+        };
+        let tab_count = self.tab_labels.len();
+        shell.publish(on_close(
+            self.model_index(index),
+            self.tab_id(index).clone(),
+        ));
+        // Only set while this is the last tab of the bar.
+        if let Some(on_empty) = self.on_empty {
+            shell.publish(on_empty());
+        }
+
+        // Hand the selection to the right neighbor, or the left one if the
+        // last tab was closed. Its index is the one it will have once the tab
+        // is gone.
+        if self.select_neighbor_on_close && self.active_tab == Some(index) {
+            let neighbor = if index + 1 < tab_count {
+                Some(index + 1)
+            } else {
+                index.checked_sub(1)
+            };
+            if let Some(neighbor) = neighbor {
+                let (closed, model_neighbor) =
+                    (self.model_index(index), self.model_index(neighbor));
+                let new_index = if model_neighbor > closed {
+                    model_neighbor - 1
+                } else {
+                    model_neighbor
+                };
+                shell.publish((self.on_select)(new_index, self.tab_id(neighbor).clone()));
+            }
+        }
+
+        if !self.close_animation.is_zero() {
+            let label = self.display_label(content_state, index).into_owned();
+            content_state.closing = self
+                .tab_layouts(layout)
+                .get(index)
+                .map(|tab_layout| detach_layout(*tab_layout, layout.position()))
+                .map(|node| ClosingTab {
+                    index,
+                    id: Rc::new(self.tab_id(index).clone()),
+                    label,
+                    status: content_state.tab_statuses[self.stored_index(index)],
+                    background: self
+                        .tab_backgrounds
+                        .get(self.stored_index(index))
+                        .copied()
+                        .flatten(),
+                    close_color: self
+                        .tab_close_colors
+                        .get(self.stored_index(index))
+                        .copied()
+                        .flatten(),
+                    node,
+                    requested: Instant::now(),
+                    removed: None,
+                });
+        }
+    }
+
+    /// Returns the hover cross-fade progress of the tab at `index`, if a hover
+    /// animation is set.
+    fn hover_fade(&self, content_state: &TabBarContentState, index: usize) -> Option<f32> {
+        if self.hover_animation.is_zero() {
+            return None;
+        }
+        content_state
+            .hover_fades
+            .get(index)
+            .map(|fade| fade.progress(self.hover_animation))
+    }
+
+    /// Ends renaming, publishing the new name if it is non-empty and changed.
+    fn commit_rename(
+        &self,
+        content_state: &mut TabBarContentState,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(rename) = content_state.renaming.take() else {
+            return;
+        };
+        let value = rename.value.trim();
+        if let Some(on_rename) = self.on_rename
+            && !value.is_empty()
+            && value != rename.original
+        {
+            shell.publish(on_rename(
+                self.tab_id(rename.index).clone(),
+                value.to_owned(),
+            ));
+        }
+        shell.request_redraw();
+    }
+
+    /// Whether the tab being animated out has been removed by the
+    /// application.
+    fn is_closed(&self, closing: &ClosingTab) -> bool {
+        closing
+            .id
+            .downcast_ref::<TabId>()
+            .is_some_and(|id| !self.tab_indices.contains(id))
+    }
+
+    /// Whether a tab is currently offered to other bars. Only the row that is
+    /// dragging can be the one offering it.
+    fn is_dragged_out(&self) -> bool {
+        self.drag_channel
+            .is_some_and(|channel| channel.borrow().as_ref().is_some_and(|e| !e.released))
+    }
+
+    /// Takes back a tab of this row offered to other bars, e.g. when its drag
+    /// is cancelled.
+    fn withdraw_external_drag(&self) {
+        if let Some(channel) = self.drag_channel {
+            let mut external = channel.borrow_mut();
+            if external.as_ref().is_some_and(|e| !e.released) {
+                *external = None;
+            }
+        }
+    }
+}
+
+/// An entry of the tab row.
+#[derive(Clone, Copy, Debug)]
+enum RowEntry<'a> {
+    /// The tab at the given index.
+    Tab(usize),
+    /// A section header with the given label.
+    Section(&'a str),
+}
+
+/// Returns the range of tab indices sharing a section with the tab at `index`.
+///
+/// Section headers act as fixed boundaries: tabs are only reordered within
+/// their own section.
+pub(crate) fn section_group(
+    sections: &[(usize, String)],
+    index: usize,
+    count: usize,
+) -> std::ops::Range<usize> {
+    let start = sections
+        .iter()
+        .map(|(position, _)| *position)
+        .filter(|&position| position <= index)
+        .max()
+        .unwrap_or(0);
+    let end = sections
+        .iter()
+        .map(|(position, _)| *position)
+        .filter(|&position| position > index)
+        .min()
+        .unwrap_or(count)
+        .min(count);
+    start..end
+}
+
+/// Returns the range of tab indices the tab at `index` can be moved within.
+///
+/// Like [`section_group`], but locked tabs also act as boundaries, so no tab
+/// can be moved past them.
+pub(crate) fn reorder_range(
+    sections: &[(usize, String)],
+    locked: &[bool],
+    index: usize,
+    count: usize,
+) -> std::ops::Range<usize> {
+    let group = section_group(sections, index, count);
+    let is_locked = |i: &usize| locked.get(*i).copied().unwrap_or(false);
+    let start = (group.start..index)
+        .rev()
+        .find(is_locked)
+        .map_or(group.start, |i| i + 1);
+    let end = (index + 1..group.end).find(is_locked).unwrap_or(group.end);
+    start..end
 }
 
 fn layout_icon<Theme, Renderer>(
@@ -298,13 +1315,18 @@ where
 
 /// Builds a single tab's layout row (label content + optional close button).
 ///
+/// With `collapse`, icon-and-text labels show only their icon and text labels
+/// are at most that wide.
+///
 /// Used by both `Tab::row_element` and `DragTabOverlay::layout`.
 #[allow(clippy::too_many_arguments)]
-pub(crate) fn build_single_tab_row<'a, Message: 'a, Theme: 'a, Renderer: 'a>(
+pub(crate) fn build_single_tab_row<'a, Message, Theme, Renderer>(
     tab_label: &'a TabLabel,
     icon_size: f32,
     text_size: f32,
     close_size: f32,
+    close_hit_area: f32,
+    layout_size_offset: f32,
     close_spacing: f32,
     icon_spacing: f32,
     padding: Padding,
@@ -314,30 +1336,32 @@ pub(crate) fn build_single_tab_row<'a, Message: 'a, Theme: 'a, Renderer: 'a>(
     position: Position,
     font: Option<Font>,
     text_font: Option<Font>,
+    collapse: Option<f32>,
 ) -> Row<'a, Message, Theme, Renderer>
 where
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
-    Theme: Catalog + text::Catalog + container::Catalog,
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: 'a + Catalog + text::Catalog + container::Catalog,
 {
     let mut label_row = Row::new()
         .push(
             match tab_label {
-                TabLabel::Icon(icon) => {
-                    Container::new(layout_icon(icon, icon_size + LAYOUT_SIZE_OFFSET, font))
-                        .align_x(Horizontal::Center)
-                        .align_y(Vertical::Center)
+                TabLabel::Text(text) => {
+                    let text =
+                        layout_text(text.as_str(), text_size + layout_size_offset, text_font);
+                    match collapse {
+                        Some(max_width) => {
+                            Container::new(text.wrapping(text::Wrapping::None)).max_width(max_width)
+                        }
+                        None => Container::new(text),
+                    }
+                    .align_x(Horizontal::Center)
+                    .align_y(Vertical::Center)
                 }
-                TabLabel::Text(text) => Container::new(layout_text(
-                    text.as_str(),
-                    text_size + LAYOUT_SIZE_OFFSET,
-                    text_font,
-                ))
-                .align_x(Horizontal::Center)
-                .align_y(Vertical::Center),
-                TabLabel::IconText(icon, text) => {
-                    let icon_el = layout_icon(icon, icon_size + LAYOUT_SIZE_OFFSET, font);
+                TabLabel::IconText(icon, text) if collapse.is_none() => {
+                    let icon_el = layout_icon(icon, icon_size + layout_size_offset, font);
                     let text_el =
-                        layout_text(text.as_str(), text_size + LAYOUT_SIZE_OFFSET, text_font);
+                        layout_text(text.as_str(), text_size + layout_size_offset, text_font);
                     let (first, second): (
                         Element<'_, Message, Theme, Renderer>,
                         Element<'_, Message, Theme, Renderer>,
@@ -365,6 +1389,12 @@ where
                         .align_x(Horizontal::Center)
                         .align_y(Vertical::Center)
                 }
+                // Collapsed labels keep only their icon.
+                TabLabel::Icon(icon) | TabLabel::IconText(icon, _) => {
+                    Container::new(layout_icon(icon, icon_size + layout_size_offset, font))
+                        .align_x(Horizontal::Center)
+                        .align_y(Vertical::Center)
+                }
             }
             .width(tab_width.map_or(Length::Shrink, Length::Fixed))
             .height(height),
@@ -375,19 +1405,17 @@ where
         .width(tab_width.map_or(Length::Shrink, Length::Fixed));
 
     if has_close {
+        // The hit area never shrinks below the icon itself.
+        let hit_size = close_size * close_hit_area.max(1.0) + layout_size_offset;
         label_row = label_row.push(
             Row::new()
-                .width(Length::Fixed(
-                    close_size * CLOSE_HIT_AREA_MULTIPLIER + LAYOUT_SIZE_OFFSET,
-                ))
-                .height(Length::Fixed(
-                    close_size * CLOSE_HIT_AREA_MULTIPLIER + LAYOUT_SIZE_OFFSET,
-                ))
+                .width(Length::Fixed(hit_size))
+                .height(Length::Fixed(hit_size))
                 .align_y(Alignment::Center)
                 .push(
                     Space::new()
-                        .width(close_size + LAYOUT_SIZE_OFFSET)
-                        .height(close_size + LAYOUT_SIZE_OFFSET),
+                        .width(close_size + layout_size_offset)
+                        .height(close_size + layout_size_offset),
                 ),
         );
     }
@@ -398,21 +1426,78 @@ where
 impl<Message, TabId, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Tab<'_, '_, Message, TabId, Theme, Renderer>
 where
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font> + svg::Renderer,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font> + CloseIconRenderer,
     Theme: Catalog + text::Catalog + container::Catalog,
-    TabId: Eq + Clone,
+    TabId: 'static + Eq + Clone,
 {
     fn size(&self) -> Size<Length> {
-        Size::new(Length::Shrink, self.height)
+        if self.overflow_behavior == OverflowBehavior::Wrap {
+            Size::new(Length::Shrink, Length::Shrink)
+        } else {
+            Size::new(Length::Shrink, self.row_height())
+        }
     }
 
     fn layout(&mut self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
-        let mut element = Element::new(self.row_element());
-        let tab_tree = ensure_child_tree(&mut tree.children, &mut element);
+        let content_state = tree.state.downcast_mut::<TabBarContentState>();
+        let align_offset = content_state.align_offset;
+
+        let mut layout_row = |collapsed, label_height| {
+            let mut element = Element::new(self.row_element(collapsed, label_height));
+            let tab_tree = ensure_child_tree(&mut tree.children, &mut element);
+            element.as_widget_mut().layout(
+                tab_tree,
+                renderer,
+                &limits.width(Length::Shrink).loose(),
+            )
+        };
+
+        // With `auto_height`, a fixed height is only a minimum: labels that
+        // need more room, such as an icon stacked over its text, make every
+        // tab taller.
+        let label_height = match self.height {
+            Length::Fixed(min_height) if self.auto_height => {
+                let node = layout_row(None, Length::Shrink);
+                Length::Fixed(self.tallest_label(&node).max(min_height))
+            }
+            height => height,
+        };
 
-        element
-            .as_widget_mut()
-            .layout(tab_tree, renderer, &limits.width(Length::Shrink).loose())
+        // Measure the full labels first. If they don't fit, collapse them to
+        // their icons and, if that is not enough either, shorten text labels.
+        let mut collapsed = None;
+        let mut node = layout_row(None, label_height);
+        if let Some(fit_width) = content_state.fit_width
+            && node.size().width > fit_width + 0.5
+            && self.overflow_behavior == OverflowBehavior::Wrap
+        {
+            node = wrap_row(&node, fit_width, self.spacing.0);
+        } else if let Some(fit_width) = content_state.fit_width
+            && node.size().width > fit_width + 0.5
+        {
+            collapsed = Some(f32::INFINITY);
+            node = layout_row(collapsed, label_height);
+            let excess = node.size().width - fit_width;
+            if excess > 0.5
+                && let Some(cap) = self.text_cap(&node, excess)
+            {
+                collapsed = Some(cap);
+                node = layout_row(collapsed, label_height);
+            }
+        }
+        content_state.collapsed = collapsed;
+        content_state.label_height = Some(label_height);
+
+        if align_offset > 0.0 {
+            let size = node.size();
+            let children = node
+                .children()
+                .iter()
+                .map(|child| child.clone().translate(Vector::new(align_offset, 0.0)))
+                .collect();
+            node = Node::with_children(Size::new(size.width + align_offset, size.height), children);
+        }
+        node
     }
 
     fn draw(
@@ -426,8 +1511,25 @@ where
         viewport: &Rectangle,
     ) {
         let content_state = state.state.downcast_ref::<TabBarContentState>();
-        let drag = content_state.drag.as_ref();
-        let is_dragging = drag.is_some_and(|d| d.is_dragging);
+        let (tab_layouts, section_layouts) = self.split_layouts(layout);
+        let tab_count = self.tab_labels.len().min(tab_layouts.len());
+        // A drag whose tab no longer exists (the app changed its tabs mid-drag)
+        // is ignored here and cleared on the next update. One whose tab moved
+        // is drawn at its new index.
+        let drag = content_state
+            .drag
+            .as_ref()
+            .filter(|d| d.is_dragging)
+            .and_then(|d| Some((d, self.dragged_index(d).filter(|&i| i < tab_count)?)));
+
+        content_state
+            .tooltip_style
+            .set(Catalog::style(theme, self.class, StyleArgs::new(Status::Hovered)).tooltip);
+        content_state.edge_width.set(
+            Catalog::style(theme, self.class, StyleArgs::new(Status::Active))
+                .tab
+                .border_width,
+        );
 
         let ctx = DrawCtx {
             position: self.position,
@@ -436,66 +1538,364 @@ where
             icon_data: (self.font.unwrap_or(CODICON_FONT), self.icon_size),
             text_data: (self.text_font.unwrap_or_default(), self.text_size),
             close_size: self.close_size,
+            close_icon: self.close_icon,
+            opacity: 1.0,
+            filter: self.filter,
+            hover_fade: None,
+            truncate: content_state.collapsed.is_some_and(f32::is_finite),
             viewport,
+            index: self.first_index,
+            total: self.total,
+            close_color: None,
         };
 
-        if !is_dragging {
-            // Normal (non-drag) drawing: iterate directly without collecting.
-            for ((i, tab), tab_layout) in self.tab_labels.iter().enumerate().zip(layout.children())
+        // Visual order and bounds of the tabs, used to place the separators.
+        let mut slots: Vec<(usize, Rectangle)> = Vec::with_capacity(tab_count);
+
+        if !section_layouts.is_empty() {
+            let section_style =
+                Catalog::style(theme, self.class, StyleArgs::new(Status::Inactive)).section;
+            for ((_, label), section_layout) in
+                self.row_sections().into_iter().zip(&section_layouts)
             {
-                let tab_status = self.tab_statuses.get(i).expect("Should have a status.");
-                draw_tab(renderer, tab, tab_status, tab_layout, &ctx);
+                draw_section(renderer, label, *section_layout, section_style, &ctx);
             }
-        } else if let Some(drag) = drag {
-            // Drag path needs random access, so collect into Vec.
-            let tab_layouts: Vec<_> = layout.children().collect();
-            let dragged_idx = drag.tab_index;
-            let target = compute_drop_index(&tab_layouts, drag.current_pos.x, dragged_idx);
-
-            // Build visual order: simulate removing the dragged tab and
-            // inserting it at the target position.
-            let mut visual_order: Vec<usize> = (0..tab_layouts.len())
-                .filter(|&i| i != dragged_idx)
-                .collect();
-            let insert_at = target.min(visual_order.len());
-            visual_order.insert(insert_at, dragged_idx);
+        }
+
+        if let Some((drag, dragged_idx)) = drag {
+            let tab_layouts = &tab_layouts[..tab_count];
+            // While the tab is offered to another bar, keep this row as is.
+            let target = if self.is_dragged_out() {
+                dragged_idx
+            } else {
+                self.drop_index(tab_layouts, drag.current_pos.x, dragged_idx)
+            };
 
-            // Compute correct visual x positions based on cumulative widths.
-            let start_x = tab_layouts.first().map_or(0.0, |l| l.bounds().x);
+            let visual_order = visual_order(tab_count, dragged_idx, target);
+
+            // Only the tabs between the dragged tab and its target move. Lay
+            // them out again from the left edge of that span, using their
+            // cumulative widths.
+            let moved = dragged_idx.min(target)..=dragged_idx.max(target);
             let spacing = self.spacing.0;
-            let mut visual_positions: Vec<f32> = Vec::with_capacity(visual_order.len());
-            let mut current_x = start_x;
-            for &tab_idx in &visual_order {
-                visual_positions.push(current_x);
-                current_x += tab_layouts[tab_idx].bounds().width + spacing;
+            let mut current_x = tab_layouts[*moved.start()].bounds().x;
+            for (slot, &tab_idx) in visual_order.iter().enumerate() {
+                let original_bounds = tab_layouts[tab_idx].bounds();
+                let visual_bounds = if moved.contains(&slot) {
+                    let bounds = Rectangle {
+                        x: current_x,
+                        ..original_bounds
+                    };
+                    current_x += original_bounds.width + spacing;
+                    bounds
+                } else {
+                    original_bounds
+                };
+                slots.push((tab_idx, visual_bounds));
+            }
+
+            // Mark the gap the dragged tab will drop into, below its
+            // neighbours.
+            let dragging_style = Catalog::style(
+                theme,
+                self.class,
+                StyleArgs::new(Status::Dragging).at(self.first_index + dragged_idx, self.total),
+            )
+            .tab;
+            if let Some(background) = dragging_style.drag_placeholder_background
+                && let Some(&(_, gap)) = slots.iter().find(|(i, _)| *i == dragged_idx)
+                && gap.intersects(viewport)
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: gap,
+                        border: Border {
+                            radius: dragging_style.border_radius,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
             }
 
             // Draw each non-dragged tab at its new visual position.
-            for (slot, &tab_idx) in visual_order.iter().enumerate() {
-                if tab_idx == dragged_idx {
+            for &(tab_idx, visual_bounds) in &slots {
+                // The dragged tab follows the cursor in an overlay, unless
+                // that is disabled and it is shown in its new slot instead.
+                if tab_idx == dragged_idx && self.drag_overlay {
                     continue;
                 }
 
-                let tab = &self.tab_labels[tab_idx];
-                let tab_status = self
-                    .tab_statuses
-                    .get(tab_idx)
-                    .expect("Should have a status.");
-
-                let original_bounds = tab_layouts[tab_idx].bounds();
-                let offset_x = visual_positions[slot] - original_bounds.x;
+                let tab = &*self.display_label(content_state, tab_idx);
+                let tab_status = if tab_idx == dragged_idx {
+                    &DRAGGING_STATUS
+                } else {
+                    self.tab_statuses
+                        .get(self.stored_index(tab_idx))
+                        .unwrap_or(&NO_STATUS)
+                };
+                let background = self
+                    .tab_backgrounds
+                    .get(self.stored_index(tab_idx))
+                    .copied()
+                    .flatten();
+                let ctx = DrawCtx {
+                    hover_fade: self.hover_fade(content_state, tab_idx),
+                    index: self.first_index + tab_idx,
+                    close_color: self
+                        .tab_close_colors
+                        .get(self.stored_index(tab_idx))
+                        .copied()
+                        .flatten(),
+                    ..ctx
+                };
+
+                let offset_x = visual_bounds.x - tab_layouts[tab_idx].bounds().x;
 
                 if offset_x.abs() < 0.5 {
-                    draw_tab(renderer, tab, tab_status, tab_layouts[tab_idx], &ctx);
+                    draw_tab(
+                        renderer,
+                        tab,
+                        tab_status,
+                        background,
+                        tab_layouts[tab_idx],
+                        &ctx,
+                    );
                 } else {
                     renderer.with_translation(iced::Vector::new(offset_x, 0.0), |renderer| {
-                        draw_tab(renderer, tab, tab_status, tab_layouts[tab_idx], &ctx);
+                        draw_tab(
+                            renderer,
+                            tab,
+                            tab_status,
+                            background,
+                            tab_layouts[tab_idx],
+                            &ctx,
+                        );
                     });
                 }
             }
 
-            // The dragged tab itself is rendered by DragTabOverlay (via
+            // Otherwise the dragged tab is rendered by DragTabOverlay (via
             // TabBar::overlay), so nothing more to draw here.
+        } else {
+            // While a closed tab animates out, its ghost keeps part of its
+            // slot and the tabs after it are pushed right by what is left.
+            let closing = content_state
+                .closing
+                .as_ref()
+                .filter(|c| c.index <= tab_count && self.is_closed(c))
+                .and_then(|c| {
+                    let elapsed = c.removed?.elapsed().as_secs_f32();
+                    let progress = elapsed / self.close_animation.as_secs_f32();
+                    (progress < 1.0).then(|| (c, 1.0 - (1.0 - progress).powi(2)))
+                });
+
+            let mut shift = 0.0;
+            if let Some((closing, eased)) = closing {
+                let ghost_layout = Layout::with_offset(
+                    Vector::new(layout.position().x, layout.position().y),
+                    &closing.node,
+                );
+                let ghost_bounds = ghost_layout.bounds();
+                let remaining = 1.0 - eased;
+                shift = (ghost_bounds.width + self.spacing.0) * remaining;
+
+                let ghost_ctx = DrawCtx {
+                    opacity: remaining,
+                    index: self.first_index + closing.index,
+                    close_color: closing.close_color,
+                    ..ctx
+                };
+                renderer.with_layer(
+                    Rectangle {
+                        width: ghost_bounds.width * remaining,
+                        ..ghost_bounds
+                    },
+                    |renderer| {
+                        draw_tab(
+                            renderer,
+                            &closing.label,
+                            &closing.status,
+                            closing.background,
+                            ghost_layout,
+                            &ghost_ctx,
+                        );
+                    },
+                );
+            }
+
+            for (i, tab_layout) in tab_layouts.iter().copied().enumerate().take(tab_count) {
+                let tab = &*self.display_label(content_state, i);
+                let tab_status = self
+                    .tab_statuses
+                    .get(self.stored_index(i))
+                    .unwrap_or(&NO_STATUS);
+                let background = self
+                    .tab_backgrounds
+                    .get(self.stored_index(i))
+                    .copied()
+                    .flatten();
+                let ctx = DrawCtx {
+                    hover_fade: self.hover_fade(content_state, i),
+                    index: self.first_index + i,
+                    close_color: self
+                        .tab_close_colors
+                        .get(self.stored_index(i))
+                        .copied()
+                        .flatten(),
+                    ..ctx
+                };
+                let offset_x = match closing {
+                    Some((closing, _)) if i >= closing.index => shift,
+                    _ => 0.0,
+                };
+
+                if offset_x < 0.5 {
+                    draw_tab(renderer, tab, tab_status, background, tab_layout, &ctx);
+                } else {
+                    renderer.with_translation(Vector::new(offset_x, 0.0), |renderer| {
+                        draw_tab(renderer, tab, tab_status, background, tab_layout, &ctx);
+                    });
+                }
+                slots.push((i, tab_layout.bounds() + Vector::new(offset_x, 0.0)));
+            }
+        }
+
+        let separator = Catalog::style(theme, self.class, StyleArgs::new(Status::Inactive))
+            .tab
+            .separator;
+        if separator.width > 0.0 {
+            let hides_separator = |i: usize| {
+                matches!(
+                    self.tab_statuses
+                        .get(self.stored_index(i))
+                        .and_then(|s| s.0),
+                    Some(Status::Active | Status::Hovered | Status::Pressed | Status::Dragging)
+                )
+            };
+
+            let row_sections = self.row_sections();
+            for (slot, pair) in slots.windows(2).enumerate() {
+                let ((left, left_bounds), (right, right_bounds)) = (pair[0], pair[1]);
+                let has_section_between = row_sections.iter().any(|(p, _)| *p == slot + 1);
+                // Wrapped tabs have no gap between the end of one line and
+                // the start of the next.
+                let wraps_between = right_bounds.x < left_bounds.x;
+                if has_section_between
+                    || wraps_between
+                    || hides_separator(left)
+                    || hides_separator(right)
+                {
+                    continue;
+                }
+
+                // Center the divider in the gap between the two tabs.
+                let gap_center = (left_bounds.x + left_bounds.width + right_bounds.x) / 2.0;
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: gap_center - separator.width / 2.0,
+                            y: left_bounds.y,
+                            width: separator.width,
+                            height: left_bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    separator.color,
+                );
+            }
+        }
+
+        let indicator = Catalog::style(theme, self.class, StyleArgs::new(Status::Active))
+            .tab
+            .indicator;
+        let indicator_bounds = self
+            .active_tab
+            .and_then(|active| slots.iter().find(|(i, _)| *i == active))
+            .filter(|_| indicator.width > 0.0)
+            .map(|&(_, tab)| {
+                let target = Rectangle {
+                    y: tab.y + tab.height - indicator.width,
+                    height: indicator.width,
+                    ..tab
+                };
+                match content_state.indicator_slide {
+                    Some(slide) => {
+                        let t = slide.progress(self.indicator_animation);
+                        Rectangle {
+                            x: slide.from.x + (target.x - slide.from.x) * t,
+                            width: slide.from.width + (target.width - slide.from.width) * t,
+                            ..target
+                        }
+                    }
+                    None => target,
+                }
+            });
+        if let Some(bounds) = indicator_bounds {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                indicator.color,
+            );
+        }
+        content_state.indicator_bounds.set(indicator_bounds);
+
+        // Tabs inside the scrollable are drawn scrolled by the difference
+        // between its viewport and their layout.
+        let scroll_offset = if self.scrollable {
+            viewport.position() - layout.position()
+        } else {
+            Vector::ZERO
+        };
+        content_state.active_bounds.set(
+            self.active_tab
+                .filter(|_| drag.is_none_or(|(_, dragged)| self.active_tab != Some(dragged)))
+                .and_then(|active| slots.iter().find(|(i, _)| *i == active))
+                .and_then(|&(_, tab)| tab.intersection(viewport))
+                .map(|tab| tab - scroll_offset),
+        );
+
+        // Mark where a tab dragged in from another bar would be inserted.
+        if let Some(index) = content_state.external_drop
+            && let Some(&(_, first)) = slots.first()
+            && let Some(&(_, last)) = slots.last()
+        {
+            let x = match (
+                index.checked_sub(1).and_then(|i| slots.get(i)),
+                slots.get(index),
+            ) {
+                (Some((_, left)), Some((_, right))) => (left.x + left.width + right.x) / 2.0,
+                (None, _) => first.x,
+                (Some(_), None) => last.x + last.width,
+            };
+            let style = Catalog::style(theme, self.class, StyleArgs::new(Status::Focused)).tab;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - FOCUS_RING_WIDTH / 2.0,
+                        y: first.y,
+                        width: FOCUS_RING_WIDTH,
+                        height: first.height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.focus_border_color.unwrap_or(style.text_color),
+            );
+        }
+
+        if drag.is_none()
+            && let Some(rename) = &content_state.renaming
+            && let Some(tab_layout) = tab_layouts.get(rename.index)
+            && let Some(label_layout) = tab_layout.children().next()
+        {
+            let ctx = DrawCtx {
+                index: self.first_index + rename.index,
+                ..ctx
+            };
+            draw_rename(renderer, rename, label_layout.bounds(), &ctx);
         }
     }
 
@@ -508,15 +1908,49 @@ where
             tab_statuses: self.tab_statuses.to_vec(),
             drag: None,
             tooltip: None,
+            tooltip_style: Cell::default(),
+            edge_width: Cell::default(),
+            indicator_bounds: Cell::default(),
+            active_bounds: Cell::default(),
+            indicator_tab: None,
+            indicator_slide: None,
+            closing: None,
+            renaming: None,
+            last_click: None,
+            pressed: None,
+            pending_select: None,
+            focused: None,
+            just_scrolled: false,
+            snap_after: None,
+            snap_delta: None,
+            scrolled_at: None,
+            bar_hovered: false,
+            touch_scroll: None,
+            align_offset: 0.0,
+            external_drop: None,
+            fit_width: None,
+            collapsed: None,
+            label_height: None,
+            modifiers: keyboard::Modifiers::default(),
+            hover_fades: Vec::new(),
+            reported_bounds: Vec::new(),
+            reported_overflow: None,
+            reported_edge: None,
+            tooltip_measurement: None,
         })
     }
 
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(Element::new(self.row_element()))]
+        vec![Tree::new(Element::new(
+            self.row_element(None, self.row_height()),
+        ))]
     }
 
     fn diff(&self, tree: &mut Tree) {
-        let content = Element::new(self.row_element());
+        let content_state = tree.state.downcast_ref::<TabBarContentState>();
+        let content = Element::new(
+            self.row_element(content_state.collapsed, self.label_height(content_state)),
+        );
         tree.diff_children(std::slice::from_ref(&content));
     }
 
@@ -528,9 +1962,11 @@ where
         operation: &mut dyn Operation<()>,
     ) {
         operation.container(None, layout.bounds());
+        let content_state = tree.state.downcast_ref::<TabBarContentState>();
+        let (collapsed, label_height) = (content_state.collapsed, self.label_height(content_state));
         operation.traverse(&mut |operation| {
             if let Some(tab_tree) = tree.children.get_mut(0) {
-                let row = self.row_element();
+                let row = self.row_element(collapsed, label_height);
                 let mut element = Element::new(row);
                 tab_tree.diff(element.as_widget_mut());
                 element
@@ -546,8 +1982,8 @@ where
         event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        renderer: &Renderer,
-        clipboard: &mut dyn Clipboard,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
@@ -558,53 +1994,329 @@ where
                 .tab_statuses
                 .extend_from_slice(self.tab_statuses);
         }
+        content_state
+            .tab_statuses
+            .resize(self.tab_labels.len(), NO_STATUS);
+
+        let (tab_layouts, section_layouts) = self.split_layouts(layout);
+
+        // The app may add or remove tabs while a drag is in progress (e.g. in
+        // response to `on_close`). Follow the dragged tab to its new index, or
+        // bail out of the drag if it is gone.
+        let tab_count = self.tab_labels.len();
+        let dragged = content_state.drag.as_ref().map(|d| {
+            self.dragged_index(d)
+                .filter(|_| tab_layouts.len() == tab_count)
+        });
+        if let Some(Some(index)) = dragged
+            && let Some(drag) = content_state.drag.as_mut()
+            && drag.tab_index != index
+        {
+            drag.tab_index = index;
+            drag.preview_target = index;
+        }
+        if dragged == Some(None) {
+            if content_state.drag.take().is_some_and(|d| d.is_dragging) {
+                self.withdraw_external_drag();
+                if let Some(on_drag_end) = self.on_drag_end {
+                    shell.publish(on_drag_end());
+                }
+            }
+            shell.request_redraw();
+        }
 
-        let mut element = Element::new(self.row_element());
-        let tab_tree = ensure_child_tree(&mut state.children, &mut element);
+        // Closing the focused last tab hands focus to the new last one.
+        if content_state.focused.is_some_and(|f| f >= tab_count) {
+            content_state.focused = tab_count.checked_sub(1);
+        }
 
-        element.as_widget_mut().update(
-            tab_tree, event, layout, cursor, renderer, clipboard, shell, viewport,
-        );
+        // The strip area this row owns. Inside the scrollable that is the
+        // visible part of the scrollable, which hands us a viewport shifted
+        // by its scroll offset while our own layout stays unscrolled.
+        let (strip, scroll_offset) = if self.scrollable {
+            let bounds = layout.bounds();
+            (
+                *viewport,
+                Vector::new(viewport.x - bounds.x, viewport.y - bounds.y),
+            )
+        } else {
+            (layout.bounds(), Vector::ZERO)
+        };
 
-        let tab_layouts: Vec<_> = layout.children().collect();
+        if let Some(on_tab_bounds) = self.on_tab_bounds {
+            let reported = &mut content_state.reported_bounds;
+            reported.truncate(tab_count);
+            for (i, tab_layout) in tab_layouts.iter().take(tab_count).enumerate() {
+                let id = self.tab_id(i);
+                let tab_bounds = tab_layout.bounds() - scroll_offset;
+                let unchanged = reported.get(i).is_some_and(|(reported_id, bounds)| {
+                    reported_id.downcast_ref::<TabId>() == Some(id) && *bounds == tab_bounds
+                });
+                if unchanged {
+                    continue;
+                }
+                let entry: (Rc<dyn Any>, Rectangle) = (Rc::new(id.clone()), tab_bounds);
+                if let Some(slot) = reported.get_mut(i) {
+                    *slot = entry;
+                } else {
+                    reported.push(entry);
+                }
+                shell.publish(on_tab_bounds(id.clone(), tab_bounds));
+            }
+        }
+
+        if let Some(on_overflow) = self.on_overflow
+            && self.scrollable
+        {
+            let overflowing = layout.bounds().width > viewport.width + 0.5;
+            if content_state.reported_overflow != Some(overflowing) {
+                content_state.reported_overflow = Some(overflowing);
+                shell.publish(on_overflow(overflowing));
+            }
+        }
+
+        if let Some(on_scroll_edge) = self.on_scroll_edge
+            && self.scrollable
+        {
+            let max_offset = (layout.bounds().width - viewport.width).max(0.0);
+            let offset = scroll_offset.x;
+            if max_offset <= 0.5 {
+                content_state.reported_edge = None;
+            } else {
+                // Forget the reported end only once the strip has moved well
+                // away from it, so resting on it doesn't report it again.
+                content_state.reported_edge = match content_state.reported_edge {
+                    Some(ScrollEdge::Start) if offset > SCROLL_EDGE_HYSTERESIS => None,
+                    Some(ScrollEdge::End) if offset < max_offset - SCROLL_EDGE_HYSTERESIS => None,
+                    reported => reported,
+                };
+                let edge = if offset <= 0.5 {
+                    Some(ScrollEdge::Start)
+                } else if offset >= max_offset - 0.5 {
+                    Some(ScrollEdge::End)
+                } else {
+                    None
+                };
+                if let Some(edge) = edge
+                    && content_state.reported_edge != Some(edge)
+                {
+                    content_state.reported_edge = Some(edge);
+                    shell.publish(on_scroll_edge(edge));
+                }
+            }
+        }
+
+        // Once scrolling settles, move the nearest tab edge (or either end of
+        // the strip) to the start of the viewport.
+        if let Some(scrolled) = content_state.snap_after
+            && self.scrollable
+            && content_state.drag.is_none()
+        {
+            let until = scrolled + SCROLL_SNAP_DELAY;
+            if Instant::now() >= until {
+                content_state.snap_after = None;
+                let bounds = layout.bounds();
+                let max_offset = (bounds.width - viewport.width).max(0.0);
+                let offset = scroll_offset.x;
+                content_state.snap_delta = tab_layouts
+                    .iter()
+                    .take(tab_count)
+                    .map(|tl| (tl.bounds().x - bounds.x).clamp(0.0, max_offset))
+                    .chain([0.0, max_offset])
+                    .min_by(|a, b| (a - offset).abs().total_cmp(&(b - offset).abs()))
+                    .map(|target| target - offset)
+                    .filter(|delta| delta.abs() > 0.5);
+            } else {
+                shell.request_redraw_at(until);
+            }
+        }
+
+        // Slide the indicator from where it was last drawn when another tab
+        // becomes active.
+        if content_state.indicator_tab != self.active_tab {
+            content_state.indicator_tab = self.active_tab;
+            content_state.indicator_slide = content_state
+                .indicator_bounds
+                .get()
+                .filter(|_| !self.indicator_animation.is_zero() && self.active_tab.is_some())
+                .map(|from| IndicatorSlide {
+                    from,
+                    start: Instant::now(),
+                });
+        }
+        if let Some(slide) = content_state.indicator_slide {
+            if slide.progress(self.indicator_animation) < 1.0 {
+                shell.request_redraw();
+            } else {
+                content_state.indicator_slide = None;
+            }
+        }
+
+        // Animate a closed tab out once the app has actually removed it. If
+        // it doesn't within the animation time (e.g. `on_close_request` was
+        // declined), or the tab comes back, drop the ghost.
+        if let Some(closing) = &mut content_state.closing {
+            let closed = self.is_closed(closing);
+            if closing.removed.is_none() && closed {
+                closing.removed = Some(Instant::now());
+            }
+            let finished = match closing.removed {
+                Some(removed) => !closed || removed.elapsed() >= self.close_animation,
+                None => closing.requested.elapsed() >= self.close_animation,
+            };
+            if finished {
+                content_state.closing = None;
+            }
+            shell.request_redraw();
+        }
 
         let is_currently_dragging = content_state.drag.as_ref().is_some_and(|d| d.is_dragging);
 
+        if content_state
+            .renaming
+            .as_ref()
+            .is_some_and(|r| r.index >= tab_count)
+        {
+            content_state.renaming = None;
+        }
+        if content_state.renaming.is_some()
+            && self.update_rename(content_state, event, cursor, strip, &tab_layouts, shell)
+        {
+            return;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor
+                    .position()
+                    .is_none_or(|pos| !layout.bounds().contains(pos))
+                {
+                    content_state.focused = None;
+                }
+
+                if let Some(on_edge_click) = self.on_edge_click
+                    && let Some(pos) = cursor.position()
+                    && !shell.is_event_captured()
+                    && let Some(index) = tab_layouts
+                        .iter()
+                        .take(tab_count)
+                        .position(|tl| tl.bounds().contains(pos))
+                    && !close_layout(tab_layouts[index])
+                        .is_some_and(|cl| self.has_close && cl.bounds().contains(pos))
+                {
+                    let bounds = tab_layouts[index].bounds();
+                    let band = content_state.edge_width.get().max(0.0) + EDGE_CLICK_SLOP;
+                    if pos.x - bounds.x <= band || bounds.x + bounds.width - pos.x <= band {
+                        shell.publish(on_edge_click(self.tab_id(index).clone()));
+                        content_state.last_click = None;
+                        shell.capture_event();
+                    }
+                }
+
+                // The strip includes the empty area after the last tab.
+                if (self.on_double_click.is_some()
+                    || self.on_rename.is_some()
+                    || self.on_new_tab.is_some())
+                    && let Some(pos) = cursor.position()
+                    && !shell.is_event_captured()
+                    && strip.contains(pos)
+                    && !section_layouts.iter().any(|sl| sl.bounds().contains(pos))
+                {
+                    let tab_index = tab_layouts
+                        .iter()
+                        .take(tab_count)
+                        .position(|tl| tl.bounds().contains(pos));
+                    let on_close_button = self.has_close
+                        && tab_index
+                            .and_then(|i| close_layout(tab_layouts[i]))
+                            .is_some_and(|cl| cl.bounds().contains(pos));
+
+                    let is_double_click = content_state.last_click.take().is_some_and(|last| {
+                        last.tab_index == tab_index
+                            && last.time.elapsed() <= DOUBLE_CLICK_INTERVAL
+                            && last.position.distance(pos) <= DOUBLE_CLICK_DISTANCE
+                    });
+
+                    if on_close_button {
+                        // Close clicks never take part in a double-click.
+                    } else if is_double_click {
+                        if let Some(on_double_click) = self.on_double_click {
+                            shell.publish(on_double_click(
+                                tab_index.map(|i| self.tab_id(i).clone()),
+                            ));
+                        }
+                        if let Some(on_new_tab) = self.on_new_tab
+                            && tab_index.is_none()
+                        {
+                            shell.publish(on_new_tab());
+                        }
+                        if self.on_rename.is_some()
+                            && let Some(index) = tab_index
+                            && let TabLabel::Text(text) | TabLabel::IconText(_, text) =
+                                &self.tab_labels[self.stored_index(index)]
+                        {
+                            content_state.renaming = Some(Rename {
+                                index,
+                                original: text.clone(),
+                                value: text.clone(),
+                                cursor: text.len(),
+                            });
+                            content_state.drag = None;
+                            content_state.pressed = None;
+                            shell.request_redraw();
+                        }
+                        shell.capture_event();
+                    } else {
+                        content_state.last_click = Some(LastClick {
+                            time: Instant::now(),
+                            position: pos,
+                            tab_index,
+                        });
+                    }
+                }
+
                 if let Some(pos) = cursor.position()
                     && !shell.is_event_captured()
                     && layout.bounds().contains(pos)
-                    && let Some(new_selected) =
-                        tab_layouts.iter().position(|tl| tl.bounds().contains(pos))
+                    && let Some(new_selected) = tab_layouts
+                        .iter()
+                        .take(tab_count)
+                        .position(|tl| tl.bounds().contains(pos))
                 {
                     let tab_layout = &tab_layouts[new_selected];
 
-                    let is_close_click = if let Some(on_close) = self.on_close.as_ref() {
-                        let cross_layout = tab_layout
-                            .children()
-                            .nth(1)
-                            .expect("TabBarContent: Layout should have a close layout");
-                        if cross_layout.bounds().contains(pos) {
-                            shell.publish(on_close(self.tab_indices[new_selected].clone()));
-                            shell.capture_event();
-                            true
+                    let is_close_click = self.on_close.is_some()
+                        && close_layout(*tab_layout)
+                            .expect("TabBarContent: Layout should have a close layout")
+                            .bounds()
+                            .contains(pos);
+                    if is_close_click {
+                        self.close_tab(content_state, layout, new_selected, shell);
+                        shell.capture_event();
+                    } else {
+                        if self.select_on_release {
+                            content_state.pending_select = Some(new_selected);
                         } else {
-                            false
+                            self.select(new_selected, shell);
                         }
-                    } else {
-                        false
-                    };
-
-                    if !is_close_click {
-                        shell.publish((self.on_select)(self.tab_indices[new_selected].clone()));
                         shell.capture_event();
-
-                        if self.on_reorder.is_some() {
+                        content_state.focused = Some(new_selected);
+                        content_state.pressed = Some(new_selected);
+
+                        if (self.on_reorder.is_some()
+                            || self.on_reorder_ids.is_some()
+                            || self.on_move.is_some())
+                            && !self
+                                .tab_locked
+                                .get(self.stored_index(new_selected))
+                                .copied()
+                                .unwrap_or(false)
+                        {
                             let tab_bounds = tab_layout.bounds();
                             content_state.drag = Some(DragState {
                                 tab_index: new_selected,
+                                tab_id: Rc::new(self.tab_id(new_selected).clone()),
                                 press_origin: pos,
                                 current_pos: pos,
                                 is_dragging: false,
@@ -612,6 +2324,9 @@ where
                                 tab_offset_y: pos.y - tab_bounds.y,
                                 tab_size: Size::ZERO,
                                 overlay_pos: Point::new(0.0, 0.0),
+                                press_time: Instant::now(),
+                                is_touch: matches!(event, Event::Touch(_)),
+                                preview_target: new_selected,
                             });
                         }
                     }
@@ -620,6 +2335,7 @@ where
 
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let mut cancel_drag = false;
                 if let Some(drag) = content_state.drag.as_mut()
                     && let Some(pos) = cursor.position()
                 {
@@ -627,8 +2343,20 @@ where
                     if !drag.is_dragging {
                         let dx = pos.x - drag.press_origin.x;
                         let dy = pos.y - drag.press_origin.y;
-                        if dx * dx + dy * dy >= self.drag_threshold * self.drag_threshold {
+                        let past_threshold =
+                            dx.abs() >= self.reorder_threshold || dy.abs() >= self.detach_threshold;
+                        // Touch reordering requires a long-press; a finger that
+                        // moves earlier is treated as a scroll, not a drag.
+                        let held_long_enough =
+                            !drag.is_touch || drag.press_time.elapsed() >= self.touch_reorder_delay;
+                        if past_threshold && !held_long_enough {
+                            cancel_drag = true;
+                        } else if past_threshold {
                             drag.is_dragging = true;
+                            content_state.pending_select = None;
+                            if let Some(on_drag_start) = self.on_drag_start {
+                                shell.publish(on_drag_start(self.tab_id(drag.tab_index).clone()));
+                            }
                             if let Some(tl) = tab_layouts.get(drag.tab_index) {
                                 let b = tl.bounds();
                                 drag.tab_size = Size::new(b.width, b.height);
@@ -640,38 +2368,262 @@ where
                         shell.capture_event();
                     }
                 }
+                if cancel_drag {
+                    content_state.drag = None;
+                    content_state.pending_select = None;
+                }
+
+                if let Some(channel) = self.drag_channel {
+                    let mut external = channel.borrow_mut();
+                    if let Some(drag) = content_state.drag.as_ref().filter(|d| d.is_dragging) {
+                        // Our own tab: offer it to other bars while the cursor
+                        // is pulled out of this strip, and take it back once it
+                        // returns.
+                        let detach_zone = Rectangle {
+                            y: strip.y - self.detach_threshold,
+                            height: strip.height + 2.0 * self.detach_threshold,
+                            ..strip
+                        };
+                        let outside = !cursor
+                            .land()
+                            .position()
+                            .is_some_and(|p| detach_zone.contains(p));
+                        if !outside {
+                            *external = None;
+                        } else if external.as_ref().is_none_or(|e| e.released) {
+                            *external = Some(ExternalDrag {
+                                id: self.tab_id(drag.tab_index).clone(),
+                                accepted: false,
+                                released: false,
+                            });
+                        }
+                    } else if self.on_accept_external.is_some() {
+                        // Another bar's tab: show where it would be inserted.
+                        let drop = external
+                            .as_mut()
+                            .filter(|e| !e.released)
+                            .zip(cursor.position_over(strip))
+                            .map(|(external, pos)| {
+                                external.accepted = true;
+                                insertion_index(&tab_layouts, pos.x)
+                            });
+                        if drop.is_none()
+                            && content_state.external_drop.is_some()
+                            && let Some(external) = external.as_mut()
+                        {
+                            external.accepted = false;
+                        }
+                        if drop != content_state.external_drop {
+                            content_state.external_drop = drop;
+                            shell.request_redraw();
+                        }
+                    }
+                }
+
+                if let Some(on_reorder_preview) = self.on_reorder_preview
+                    && let Some(drag) = content_state.drag.as_mut().filter(|d| d.is_dragging)
+                {
+                    let target = if self.is_dragged_out() {
+                        drag.tab_index
+                    } else {
+                        self.drop_index(&tab_layouts, drag.current_pos.x, drag.tab_index)
+                    };
+                    if target != drag.preview_target {
+                        drag.preview_target = target;
+                        shell.publish(on_reorder_preview(
+                            self.model_index(drag.tab_index),
+                            self.model_index(target),
+                        ));
+                    }
+                }
             }
 
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerLifted { .. })
             | Event::Touch(touch::Event::FingerLost { .. }) => {
+                content_state.pressed = None;
+
+                // A press that didn't turn into a drag selects its tab, as
+                // long as it is released over it.
+                if let Some(index) = content_state.pending_select.take()
+                    && index < tab_count
+                    && !matches!(event, Event::Touch(touch::Event::FingerLost { .. }))
+                    && cursor
+                        .position()
+                        .is_some_and(|pos| tab_layouts[index].bounds().contains(pos))
+                {
+                    self.select(index, shell);
+                    shell.capture_event();
+                }
+
+                // A tab from another bar dropped here. The entry stays in the
+                // channel so its own bar can still read it.
+                if let Some(index) = content_state.external_drop.take()
+                    && let Some(on_accept_external) = self.on_accept_external
+                    && let Some(channel) = self.drag_channel
+                    && let Some(external) = channel.borrow_mut().as_mut()
+                {
+                    external.released = true;
+                    shell.publish(on_accept_external(
+                        external.id.clone(),
+                        self.model_insert_index(index),
+                    ));
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+
                 if let Some(drag) = content_state.drag.take()
                     && drag.is_dragging
                 {
-                    if let Some(on_reorder) = self.on_reorder.as_ref() {
-                        let target =
-                            compute_drop_index(&tab_layouts, drag.current_pos.x, drag.tab_index);
-                        if target != drag.tab_index {
-                            shell.publish(on_reorder(drag.tab_index, target));
+                    // Our tab was dragged out: hand it over if another bar
+                    // took it, otherwise it snaps back.
+                    let handed_over = self.drag_channel.and_then(|channel| {
+                        let mut external = channel.borrow_mut();
+                        let external = external
+                            .as_mut()
+                            .filter(|e| e.id == *self.tab_id(drag.tab_index))?;
+                        external.released = true;
+                        Some(external.accepted)
+                    });
+                    let target = self.drop_index(&tab_layouts, drag.current_pos.x, drag.tab_index);
+                    if handed_over == Some(true) {
+                        if let Some(on_drag_out) = self.on_drag_out {
+                            shell.publish(on_drag_out(self.tab_id(drag.tab_index).clone()));
+                        }
+                    } else if handed_over.is_none() && target != drag.tab_index {
+                        if let Some(on_reorder) = self.on_reorder {
+                            shell.publish(on_reorder(
+                                self.model_index(drag.tab_index),
+                                self.model_index(target),
+                            ));
+                        }
+                        if let Some(on_move) = self.on_move
+                            && let Some(id) = drag.tab_id.downcast_ref::<TabId>()
+                        {
+                            shell.publish(on_move(id.clone(), self.model_index(target)));
+                        }
+                        if let Some(on_reorder_ids) = self.on_reorder_ids {
+                            let ids = visual_order(
+                                self.tab_indices.len(),
+                                self.stored_index(drag.tab_index),
+                                self.stored_index(target),
+                            )
+                            .into_iter()
+                            .map(|i| self.tab_indices[i].clone())
+                            .collect();
+                            shell.publish(on_reorder_ids(ids));
                         }
                     }
+                    if let Some(on_drag_end) = self.on_drag_end {
+                        shell.publish(on_drag_end());
+                    }
                     shell.request_redraw();
                     shell.capture_event();
                 }
             }
 
+            // Escape aborts a drag: the tab snaps back without reordering.
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if is_currently_dragging => {
+                content_state.drag = None;
+                self.withdraw_external_drag();
+                if let Some(on_drag_end) = self.on_drag_end {
+                    shell.publish(on_drag_end());
+                }
+                shell.request_redraw();
+                shell.capture_event();
+            }
+
+            // Ctrl+W, and Delete or Backspace if enabled, close the focused
+            // tab.
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                if self.keyboard_close
+                    && is_close_shortcut(key, *modifiers, self.close_on_delete)
+                    && !shell.is_event_captured()
+                    && content_state.focused.is_some_and(|f| f < tab_count) =>
+            {
+                if let Some(focused) = content_state.focused
+                    && self.on_close.is_some()
+                {
+                    // Focus stays put: the tab taking the closed one's place
+                    // inherits it, and a declined close leaves it alone.
+                    self.close_tab(content_state, layout, focused, shell);
+                    shell.capture_event();
+                }
+            }
+
+            // Left/Right move keyboard focus between tabs; Enter/Space select
+            // the focused tab.
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                modifiers,
+                ..
+            }) if modifiers.is_empty() && !shell.is_event_captured() => {
+                if let Some(focused) = content_state.focused.filter(|&f| f < tab_count) {
+                    match key {
+                        key::Named::ArrowLeft => {
+                            content_state.focused = Some(focused.saturating_sub(1));
+                            shell.capture_event();
+                        }
+                        key::Named::ArrowRight => {
+                            content_state.focused = Some((focused + 1).min(tab_count - 1));
+                            shell.capture_event();
+                        }
+                        key::Named::Enter | key::Named::Space => {
+                            self.select(focused, shell);
+                            shell.capture_event();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                content_state.modifiers = *modifiers;
+            }
+
+            // Alt+middle-click asks to close every other tab.
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle))
+                if content_state.modifiers.alt() && !is_currently_dragging =>
+            {
+                if let Some(on_close_others) = self.on_close_others
+                    && let Some(pos) = cursor.position()
+                    && let Some(index) = tab_layouts.iter().position(|tl| tl.bounds().contains(pos))
+                {
+                    shell.publish(on_close_others(self.tab_id(index).clone()));
+                    shell.capture_event();
+                }
+            }
+
             _ => {}
         }
 
+        // A wheel scroll moves the tabs under a stationary cursor, but the
+        // cursor we receive here is still translated by the pre-scroll offset.
+        // Resolving hover now would highlight the tab that *was* under the
+        // cursor, then the correct one on the next frame, which strobes when
+        // wheeling quickly across many tabs. Keep the current hover state and
+        // resolve it again on the next frame against the updated offset.
+        if std::mem::take(&mut content_state.just_scrolled) {
+            shell.request_redraw();
+            return;
+        }
+
         let mut request_redraw = false;
-        let mut hovered_tab_with_tooltip: Option<(usize, Point)> = None;
+        let mut hovered_tab_with_tooltip: Option<(usize, Point, bool)> = None;
+
+        if !self.hover_animation.is_zero() {
+            content_state
+                .hover_fades
+                .resize_with(tab_count, HoverFade::default);
+        }
 
         for ((i, _tab), tab_layout) in self.tab_labels.iter().enumerate().zip(&tab_layouts) {
-            let active_idx = self.active_tab;
-            let tab_status = content_state
-                .tab_statuses
-                .get_mut(i)
-                .expect("Should have a status.");
+            let Some(tab_status) = content_state.tab_statuses.get_mut(self.stored_index(i)) else {
+                continue;
+            };
 
             let current_status = if is_currently_dragging
                 && content_state
@@ -679,30 +2631,72 @@ where
                     .as_ref()
                     .is_some_and(|d| d.tab_index == i)
             {
-                Status::Dragging
-            } else if i == active_idx {
+                Status::Dragging
+            } else if content_state.pressed == Some(i)
+                && !is_currently_dragging
+                && cursor.is_over(tab_layout.bounds())
+            {
+                Status::Pressed
+            } else if Some(i) == self.active_tab {
                 Status::Active
+            } else if content_state.focused == Some(i) {
+                Status::Focused
             } else if cursor.is_over(tab_layout.bounds()) && !is_currently_dragging {
                 Status::Hovered
             } else {
                 Status::Inactive
             };
 
-            // Track which tab with a tooltip is being hovered.
-            if !is_currently_dragging
-                && cursor.is_over(tab_layout.bounds())
-                && self.tab_tooltips.get(i).is_some_and(|t| t.is_some())
+            if let Some(fade) = content_state.hover_fades.get_mut(i)
+                && !self.hover_animation.is_zero()
             {
-                if let Some(pos) = cursor.position() {
-                    hovered_tab_with_tooltip = Some((i, pos));
+                let hovered = current_status == Status::Hovered;
+                if fade.hovered != hovered {
+                    *fade = HoverFade {
+                        hovered,
+                        from: fade.progress(self.hover_animation),
+                        start: Instant::now(),
+                    };
+                }
+                let target = if hovered { 1.0 } else { 0.0 };
+                if fade.progress(self.hover_animation) != target {
+                    request_redraw = true;
                 }
             }
 
             let mut is_cross_hovered = None;
-            if self.has_close && !is_currently_dragging {
-                let mut tab_children = tab_layout.children();
-                if let Some(cross_layout) = tab_children.next_back() {
-                    is_cross_hovered = Some(cursor.is_over(cross_layout.bounds()));
+            if self.has_close
+                && !is_currently_dragging
+                && let Some(cross_layout) = close_layout(*tab_layout)
+            {
+                is_cross_hovered = Some(cursor.is_over(cross_layout.bounds()));
+            }
+
+            // Track which tab with a tooltip is being hovered. The close
+            // tooltip takes precedence over the tab's own tooltip.
+            if !is_currently_dragging
+                && cursor.is_over(tab_layout.bounds())
+                && let Some(pos) = cursor.position()
+            {
+                if self.has_close_tooltip && is_cross_hovered == Some(true) {
+                    hovered_tab_with_tooltip = Some((i, pos, true));
+                } else if matches!(
+                    self.tab_tooltips.get(self.stored_index(i)),
+                    Some(Some(TooltipContent::Element(_)))
+                ) || tooltip_text(
+                    self.tab_tooltips
+                        .get(self.stored_index(i))
+                        .and_then(Option::as_ref)
+                        .and_then(TooltipContent::text),
+                    self.tab_names
+                        .get(self.stored_index(i))
+                        .and_then(Option::as_ref),
+                    &self.tab_labels[self.stored_index(i)],
+                    self.auto_tooltip || self.tab_collapse(i, content_state.collapsed).is_some(),
+                )
+                .is_some()
+                {
+                    hovered_tab_with_tooltip = Some((i, pos, false));
                 }
             }
 
@@ -714,20 +2708,27 @@ where
 
         // Update tooltip hover tracking.
         match (&mut content_state.tooltip, hovered_tab_with_tooltip) {
-            (Some(ts), Some((idx, pos))) if ts.tab_index == idx => {
+            (Some(ts), Some((idx, pos, is_close)))
+                if ts.tab_index == idx && ts.is_close == is_close =>
+            {
                 ts.cursor_pos = pos;
-                if ts.hover_start.elapsed() < self.tooltip_delay {
-                    request_redraw = true;
-                }
+                ts.tab_bounds = tab_layouts[idx].bounds() - scroll_offset;
+                request_tooltip_redraw(shell, ts.hover_start, self.tooltip_delay);
             }
-            (_, Some((idx, pos))) => {
-                // Started hovering a new tab with a tooltip.
+            (_, Some((idx, pos, is_close))) => {
+                // Started hovering a new tab (or close button) with a tooltip.
+                let hover_start = Instant::now();
                 content_state.tooltip = Some(TooltipState {
                     tab_index: idx,
-                    hover_start: Instant::now(),
+                    hover_start,
                     cursor_pos: pos,
+                    is_close,
+                    tab_bounds: tab_layouts[idx].bounds() - scroll_offset,
                 });
+                // Redraw now so the old tooltip goes away, or the new one
+                // shows right away with no delay.
                 request_redraw = true;
+                request_tooltip_redraw(shell, hover_start, self.tooltip_delay);
             }
             (Some(_), None) => {
                 // Cursor left all tooltip-bearing tabs.
@@ -745,47 +2746,141 @@ where
     fn mouse_interaction(
         &self,
         state: &Tree,
-        _layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
         let content_state = state.state.downcast_ref::<TabBarContentState>();
 
         if content_state.drag.as_ref().is_some_and(|d| d.is_dragging) {
-            return mouse::Interaction::Grabbing;
+            return self.drag_cursor;
         }
 
-        mouse::Interaction::default()
+        // Tabs and their close buttons are clickable; the tab being renamed
+        // is a text field.
+        let (tab_layouts, _) = self.split_layouts(layout);
+        match tab_layouts
+            .iter()
+            .position(|tab_layout| cursor.is_over(tab_layout.bounds()))
+        {
+            Some(i)
+                if content_state
+                    .renaming
+                    .as_ref()
+                    .is_some_and(|rename| rename.index == i) =>
+            {
+                mouse::Interaction::Text
+            }
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::default(),
+        }
     }
 }
 
-/// Compute the target insertion index for a drag operation.
+/// Returns the close button's layout within a single tab's layout, if any.
 ///
-/// Compares the cursor's x position against each tab layout's center-x.
-/// Returns the index where the dragged tab should be placed.
-fn compute_drop_index(tab_layouts: &[Layout<'_>], cursor_x: f32, dragged_index: usize) -> usize {
-    let count = tab_layouts.len();
-    if count == 0 {
-        return 0;
-    }
+/// Tabs are laid out by [`build_single_tab_row`] as `[label, close]`, so the
+/// close button is always the second child. Used by hit-testing, hover
+/// tracking and drawing alike so they never disagree.
+pub(crate) fn close_layout(tab_layout: Layout<'_>) -> Option<Layout<'_>> {
+    tab_layout.children().nth(1)
+}
+
+/// Returns, for each tab, the x coordinate where a dragged tab flips from
+/// being inserted before it to after it.
+///
+/// Each tab owns the span from the middle of the gap before it to the middle
+/// of the gap after it, so the spacing between tabs is split evenly between
+/// its neighbours. The insertion point flips at the midpoint of that span.
+fn slot_midpoints(tab_layouts: &[Layout<'_>]) -> Vec<f32> {
+    let bounds: Vec<Rectangle> = tab_layouts.iter().map(Layout::bounds).collect();
+    (0..bounds.len())
+        .map(|i| {
+            let b = bounds[i];
+            let start = match i.checked_sub(1).map(|prev| bounds[prev]) {
+                Some(prev) => (prev.x + prev.width + b.x) / 2.0,
+                None => b.x,
+            };
+            let end = match bounds.get(i + 1) {
+                Some(next) => (b.x + b.width + next.x) / 2.0,
+                None => b.x + b.width,
+            };
+            (start + end) / 2.0
+        })
+        .collect()
+}
 
-    let mut target = count;
-    for (i, tab_layout) in tab_layouts.iter().enumerate() {
-        let center = tab_layout.bounds().center_x();
-        if cursor_x < center {
-            target = i;
-            break;
+/// Copies `layout` into a node placed relative to `origin`, e.g. to keep a
+/// tab's layout after the tab is gone.
+fn detach_layout(layout: Layout<'_>, origin: Point) -> Node {
+    let bounds = layout.bounds();
+    let children = layout
+        .children()
+        .map(|child| detach_layout(child, bounds.position()))
+        .collect();
+    Node::with_children(bounds.size(), children)
+        .move_to(Point::ORIGIN + (bounds.position() - origin))
+}
+
+/// Moves the entries of the row laid out as `node` onto as many lines of
+/// `width` as they need. Each line is as tall as the row, and entries keep
+/// their vertical alignment within it.
+fn wrap_row(node: &Node, width: f32, spacing: f32) -> Node {
+    let line_height = node.size().height;
+    let mut children = Vec::with_capacity(node.children().len());
+    let (mut x, mut y, mut right) = (0.0, 0.0, 0.0_f32);
+    for child in node.children() {
+        let bounds = child.bounds();
+        if x > 0.0 && x + bounds.width > width + 0.5 {
+            x = 0.0;
+            y += line_height + spacing;
         }
+        children.push(child.clone().move_to(Point::new(x, y + bounds.y)));
+        right = right.max(x + bounds.width);
+        x += bounds.width + spacing;
     }
+    Node::with_children(Size::new(right, y + line_height), children)
+}
 
-    // When dragging right, the visual slot shifts because we remove from the left.
-    // Adjust so "dropping in the same place" doesn't move.
-    if target > dragged_index {
-        target = target.saturating_sub(1);
-    }
+/// Returns where a tab from outside the row would be inserted when dropped
+/// at `cursor_x`.
+fn insertion_index(tab_layouts: &[Layout<'_>], cursor_x: f32) -> usize {
+    let midpoints = slot_midpoints(tab_layouts);
+    midpoints
+        .iter()
+        .position(|&midpoint| cursor_x < midpoint)
+        .unwrap_or(midpoints.len())
+}
+
+/// Compute the target index for a drag operation.
+///
+/// Each tab owns the span from the middle of the gap before it to the middle
+/// of the gap after it, so the spacing between tabs is split evenly between
+/// its neighbours. The dragged tab moves into the slot whose span the cursor
+/// is over. Its own span, half of each gap next to it included, maps back to
+/// where it started, so releasing a tab near its origin never reorders.
+fn compute_drop_index(tab_layouts: &[Layout<'_>], cursor_x: f32) -> usize {
+    tab_layouts
+        .windows(2)
+        .map(|pair| {
+            let (prev, next) = (pair[0].bounds(), pair[1].bounds());
+            (prev.x + prev.width + next.x) / 2.0
+        })
+        .take_while(|&boundary| cursor_x >= boundary)
+        .count()
+}
 
-    target
+/// Computes the order of tab indices after moving the tab at `from` to `to`.
+///
+/// Simulates removing the dragged tab and inserting it at the target position.
+pub(crate) fn visual_order(count: usize, from: usize, to: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..count).filter(|&i| i != from).collect();
+    if from < count {
+        let insert_at = to.min(order.len());
+        order.insert(insert_at, from);
+    }
+    order
 }
 
 /// Bundles the common parameters shared across all `draw_tab` calls within a
@@ -797,7 +2892,286 @@ struct DrawCtx<'a, 'b, Theme: Catalog> {
     icon_data: (Font, f32),
     text_data: (Font, f32),
     close_size: f32,
+    close_icon: &'a CloseIconSource,
+    /// Opacity multiplier applied to the resolved style.
+    opacity: f32,
+    /// Text that tab labels are matched against, if any.
+    filter: Option<&'a str>,
+    /// How far the tab is faded from its inactive to its hovered style, if
+    /// a hover animation is set.
+    hover_fade: Option<f32>,
+    /// Whether text labels may be narrower than their text and need to be
+    /// shortened.
+    truncate: bool,
     viewport: &'a Rectangle,
+    /// Position of the tab being drawn in the bar, and the number of tabs.
+    index: usize,
+    total: usize,
+    /// Close icon color of the tab being drawn, replacing the text color.
+    close_color: Option<Color>,
+}
+
+impl<Theme: Catalog> DrawCtx<'_, '_, Theme> {
+    /// Returns the style of the tab being drawn with the given status.
+    fn style(&self, status: Status) -> Style {
+        Catalog::style(
+            self.theme,
+            self.class,
+            StyleArgs::new(status).at(self.index, self.total),
+        )
+    }
+}
+
+/// Draws a section header: its background and its centered label.
+fn draw_section<Theme, Renderer>(
+    renderer: &mut Renderer,
+    label: &str,
+    layout: Layout<'_>,
+    style: SectionStyle,
+    ctx: &DrawCtx<'_, '_, Theme>,
+) where
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: Catalog,
+{
+    use iced::advanced::widget::text::{LineHeight, Wrapping};
+
+    let bounds = layout.bounds();
+    if !bounds.intersects(ctx.viewport) {
+        return;
+    }
+
+    if let Some(background) = style.background {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            background,
+        );
+    }
+
+    let text_bounds = layout.children().next().map_or(bounds, |l| l.bounds());
+    renderer.fill_text(
+        iced::advanced::text::Text {
+            content: label.to_owned(),
+            bounds: Size::new(text_bounds.width, text_bounds.height),
+            size: Pixels(ctx.text_data.1),
+            font: ctx.text_data.0,
+            align_x: text::Alignment::Center,
+            align_y: Vertical::Center,
+            line_height: LineHeight::default(),
+            shaping: text::Shaping::Auto,
+            wrapping: Wrapping::default(),
+        },
+        Point::new(text_bounds.center_x(), text_bounds.center_y()),
+        style.text_color,
+        bounds,
+    );
+}
+
+/// Draws the in-place editor of a tab label over the label's bounds.
+fn draw_rename<Theme, Renderer>(
+    renderer: &mut Renderer,
+    rename: &Rename,
+    bounds: Rectangle,
+    ctx: &DrawCtx<'_, '_, Theme>,
+) where
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: Catalog,
+{
+    use iced::advanced::text::Paragraph;
+    use iced::advanced::widget::text::{LineHeight, Wrapping};
+
+    const FIELD_PADDING: f32 = 2.0;
+
+    let style = ctx.style(Status::Active).tab;
+    let text = |content| iced::advanced::text::Text {
+        content,
+        bounds: Size::new(f32::INFINITY, bounds.height),
+        size: Pixels(ctx.text_data.1),
+        font: ctx.text_data.0,
+        align_x: text::Alignment::Left,
+        align_y: Vertical::Center,
+        line_height: LineHeight::Relative(1.3),
+        shaping: text::Shaping::Auto,
+        wrapping: Wrapping::None,
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                radius: style.border_radius,
+                width: 1.0,
+                color: style.focus_border_color.unwrap_or(style.text_color),
+            },
+            ..renderer::Quad::default()
+        },
+        style.background,
+    );
+
+    // Keep the caret in view by shifting long text to the left.
+    let caret_x = <Renderer as iced::advanced::text::Renderer>::Paragraph::with_text(text(
+        &rename.value[..rename.cursor],
+    ))
+    .min_width();
+    let inner_width = bounds.width - 2.0 * FIELD_PADDING;
+    let scroll = (caret_x - inner_width + 1.0).max(0.0);
+    let origin_x = bounds.x + FIELD_PADDING - scroll;
+
+    renderer.with_layer(bounds, |renderer| {
+        renderer.fill_text(
+            text(&rename.value).with_content(rename.value.clone()),
+            Point::new(origin_x, bounds.center_y()),
+            style.text_color,
+            bounds,
+        );
+
+        let caret_height = ctx.text_data.1 * 1.3;
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: origin_x + caret_x,
+                    y: bounds.center_y() - caret_height / 2.0,
+                    width: 1.0,
+                    height: caret_height,
+                },
+                ..renderer::Quad::default()
+            },
+            style.text_color,
+        );
+    });
+}
+
+/// Draws the text of a tab label centered in `bounds`, with the part matching
+/// the filter in the style's match color.
+fn draw_label_text<Theme, Renderer>(
+    renderer: &mut Renderer,
+    text: &str,
+    bounds: Rectangle,
+    filter_match: Option<std::ops::Range<usize>>,
+    style: &Style,
+    ctx: &DrawCtx<'_, '_, Theme>,
+) where
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: Catalog,
+{
+    use iced::advanced::text::Paragraph;
+    use iced::advanced::widget::text::{LineHeight, Wrapping};
+
+    let label = |content: &str, align_x| iced::advanced::text::Text {
+        content: content.to_owned(),
+        bounds: Size::new(bounds.width, bounds.height),
+        size: Pixels(ctx.text_data.1),
+        font: ctx.text_data.0,
+        align_x,
+        align_y: Vertical::Center,
+        line_height: LineHeight::Relative(1.3),
+        shaping: text::Shaping::Auto,
+        wrapping: Wrapping::default(),
+    };
+
+    let width = |content: &str| {
+        <Renderer as iced::advanced::text::Renderer>::Paragraph::with_text(
+            label(content, text::Alignment::Left).as_ref(),
+        )
+        .min_width()
+    };
+
+    // Shorten text that doesn't fit, ending it with an ellipsis.
+    let shortened;
+    let (text, filter_match) = if ctx.truncate && width(text) > bounds.width + 0.5 {
+        let ends: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        let fitting = ends.partition_point(|&end| {
+            width(&format!("{}\u{2026}", &text[..end])) <= bounds.width + 0.5
+        });
+        let end = ends[fitting.saturating_sub(1)];
+        shortened = format!("{}\u{2026}", text[..end].trim_end());
+        (
+            shortened.as_str(),
+            filter_match.filter(|range| range.end <= end),
+        )
+    } else {
+        (text, filter_match)
+    };
+
+    let Some(range) = filter_match else {
+        renderer.fill_text(
+            label(text, text::Alignment::Center),
+            Point::new(bounds.center_x(), bounds.center_y()),
+            style.tab.text_color,
+            bounds,
+        );
+        return;
+    };
+
+    // Draw the text as three left-aligned runs, placed where they would be in
+    // the centered text.
+    let match_color = style.tab.filter_match_color.unwrap_or(style.tab.text_color);
+    let mut x = bounds.center_x() - width(text) / 2.0;
+    for (run, color) in [
+        (&text[..range.start], style.tab.text_color),
+        (&text[range.clone()], match_color),
+        (&text[range.end..], style.tab.text_color),
+    ] {
+        if run.is_empty() {
+            continue;
+        }
+        renderer.fill_text(
+            label(run, text::Alignment::Left),
+            Point::new(x, bounds.center_y()),
+            color,
+            bounds,
+        );
+        x += width(run);
+    }
+}
+
+/// Blends two backgrounds. Only solid colors are blended; other backgrounds
+/// switch over halfway.
+fn mix_background(a: Background, b: Background, factor: f32) -> Background {
+    match (a, b) {
+        (Background::Color(a), Background::Color(b)) => Background::Color(mix(a, b, factor)),
+        _ if factor < 0.5 => a,
+        _ => b,
+    }
+}
+
+/// Whether a key press is one of the shortcuts closing the focused tab.
+fn is_close_shortcut(
+    key: &keyboard::Key,
+    modifiers: keyboard::Modifiers,
+    close_on_delete: bool,
+) -> bool {
+    match key.as_ref() {
+        keyboard::Key::Named(key::Named::Delete | key::Named::Backspace) => {
+            close_on_delete && modifiers.is_empty()
+        }
+        keyboard::Key::Character("w") => modifiers == keyboard::Modifiers::COMMAND,
+        _ => false,
+    }
+}
+
+/// Returns the byte range of the first case-insensitive occurrence of
+/// `needle` in `haystack`.
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<std::ops::Range<usize>> {
+    let needle: Vec<char> = needle.chars().flat_map(char::to_lowercase).collect();
+    if needle.is_empty() {
+        return None;
+    }
+
+    haystack.char_indices().find_map(|(start, _)| {
+        let mut rest = needle.iter();
+        for (offset, c) in haystack[start..].char_indices() {
+            if !c.to_lowercase().all(|lower| rest.next() == Some(&lower)) {
+                return None;
+            }
+            if rest.len() == 0 {
+                return Some(start..start + offset + c.len_utf8());
+            }
+        }
+        None
+    })
 }
 
 #[allow(clippy::too_many_lines)]
@@ -805,14 +3179,14 @@ fn draw_tab<Theme, Renderer>(
     renderer: &mut Renderer,
     tab: &TabLabel,
     tab_status: &(Option<Status>, Option<bool>),
+    background: Option<Background>,
     layout: Layout<'_>,
     ctx: &DrawCtx<'_, '_, Theme>,
 ) where
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font> + svg::Renderer,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font> + CloseIconRenderer,
     Theme: Catalog + text::Catalog,
 {
     use iced::advanced::widget::text::{LineHeight, Wrapping};
-    use iced::{Background, Border, Color};
 
     fn child_bounds(item: Option<Layout<'_>>) -> Rectangle {
         item.expect("Graphics: Layout should have a child layout")
@@ -821,11 +3195,35 @@ fn draw_tab<Theme, Renderer>(
 
     let bounds = layout.bounds();
 
-    let style = Catalog::style(
-        ctx.theme,
-        ctx.class,
-        tab_status.0.unwrap_or(Status::Inactive),
-    );
+    let mut style = ctx.style(tab_status.0.unwrap_or(Status::Inactive));
+    // Blend between the inactive and hovered styles while the hover fades.
+    if let Some(progress) = ctx.hover_fade
+        && matches!(tab_status.0, Some(Status::Inactive | Status::Hovered))
+    {
+        let inactive = ctx.style(Status::Inactive).tab;
+        let hovered = ctx.style(Status::Hovered).tab;
+        style.tab.background = mix_background(inactive.background, hovered.background, progress);
+        style.tab.text_color = mix(inactive.text_color, hovered.text_color, progress);
+        style.tab.icon_color = mix(inactive.icon_color, hovered.icon_color, progress);
+    }
+    if let Some(background) = background {
+        style.tab.background = background;
+    }
+    let mut close_color = ctx.close_color;
+    if ctx.opacity < 1.0 {
+        style = style.scale_alpha(ctx.opacity.max(0.0));
+        close_color = close_color.map(|color| color.scale_alpha(ctx.opacity.max(0.0)));
+    }
+
+    // Tabs not matching the filter are dimmed.
+    let filter_match = ctx.filter.and_then(|filter| match tab {
+        TabLabel::Text(text) | TabLabel::IconText(_, text) => find_ignore_case(text, filter),
+        TabLabel::Icon(_) => None,
+    });
+    if ctx.filter.is_some() && filter_match.is_none() {
+        style = style.scale_alpha(FILTER_DIM_ALPHA);
+        close_color = close_color.map(|color| color.scale_alpha(FILTER_DIM_ALPHA));
+    }
 
     let mut children = layout.children();
     let label_layout = children
@@ -847,6 +3245,21 @@ fn draw_tab<Theme, Renderer>(
             },
             style.tab.background,
         );
+
+        if let Some(focus_color) = style.tab.focus_border_color {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: style.tab.border_radius,
+                        width: FOCUS_RING_WIDTH,
+                        color: focus_color,
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
     }
 
     match tab {
@@ -874,22 +3287,7 @@ fn draw_tab<Theme, Renderer>(
         TabLabel::Text(text) => {
             let text_bounds = child_bounds(label_layout_children.next());
 
-            renderer.fill_text(
-                iced::advanced::text::Text {
-                    content: text.clone(),
-                    bounds: Size::new(text_bounds.width, text_bounds.height),
-                    size: Pixels(ctx.text_data.1),
-                    font: ctx.text_data.0,
-                    align_x: text::Alignment::Center,
-                    align_y: Vertical::Center,
-                    line_height: LineHeight::Relative(1.3),
-                    shaping: text::Shaping::Auto,
-                    wrapping: Wrapping::default(),
-                },
-                Point::new(text_bounds.center_x(), text_bounds.center_y()),
-                style.tab.text_color,
-                text_bounds,
-            );
+            draw_label_text(renderer, text, text_bounds, filter_match, &style, ctx);
         }
         TabLabel::IconText(icon, text) => {
             let mut inner_children = label_layout_children
@@ -921,140 +3319,282 @@ fn draw_tab<Theme, Renderer>(
                 icon_bounds,
             );
 
-            renderer.fill_text(
-                iced::advanced::text::Text {
-                    content: text.clone(),
-                    bounds: Size::new(text_bounds.width, text_bounds.height),
-                    size: Pixels(ctx.text_data.1),
-                    font: ctx.text_data.0,
-                    align_x: text::Alignment::Center,
-                    align_y: Vertical::Center,
-                    line_height: LineHeight::Relative(1.3),
-                    shaping: text::Shaping::Auto,
-                    wrapping: Wrapping::default(),
-                },
-                Point::new(text_bounds.center_x(), text_bounds.center_y()),
-                style.tab.text_color,
-                text_bounds,
-            );
+            draw_label_text(renderer, text, text_bounds, filter_match, &style, ctx);
         }
     }
 
-    if let Some(cross_layout) = children.next() {
+    if let Some(cross_layout) = close_layout(layout) {
         let cross_bounds = cross_layout.bounds();
         let is_mouse_over_cross = tab_status.1.unwrap_or(false);
 
-        let handle = CLOSE_SVG_HANDLE.clone();
-        let svg_size = ctx.close_size + if is_mouse_over_cross { 1.0 } else { 0.0 };
-        let svg_bounds = Rectangle {
-            x: cross_bounds.center_x() - svg_size / 2.0,
-            y: cross_bounds.center_y() - svg_size / 2.0,
-            width: svg_size,
-            height: svg_size,
-        };
-        renderer.draw_svg(
-            svg::Svg::new(handle).color(style.tab.text_color),
-            svg_bounds,
-            cross_bounds,
-        );
-
-        if is_mouse_over_cross && cross_bounds.intersects(ctx.viewport) {
+        if is_mouse_over_cross
+            && cross_bounds.intersects(ctx.viewport)
+            && let Some(background) = style.tab.close_hover_background
+        {
             renderer.fill_quad(
                 renderer::Quad {
                     bounds: cross_bounds,
                     border: Border {
-                        radius: style.tab.icon_border_radius,
-                        width: style.bar.border_width,
-                        color: style.bar.border_color.unwrap_or(Color::TRANSPARENT),
+                        radius: style.tab.close_border_radius,
+                        ..Border::default()
                     },
-                    shadow: style.tab.shadow,
                     ..renderer::Quad::default()
                 },
-                style
-                    .tab
-                    .icon_background
-                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                background,
+            );
+        }
+
+        let icon_size = ctx.close_size + if is_mouse_over_cross { 1.0 } else { 0.0 };
+        let close_color = close_color.unwrap_or(style.tab.text_color);
+        let icon_color = if is_mouse_over_cross {
+            style.tab.close_hover_icon_color.unwrap_or(close_color)
+        } else {
+            close_color
+        };
+
+        let glyph = match ctx.close_icon {
+            #[cfg(feature = "svg")]
+            source @ (CloseIconSource::Svg | CloseIconSource::CustomSvg(_)) => {
+                let handle = match source {
+                    CloseIconSource::CustomSvg(handle) => handle.clone(),
+                    _ => CLOSE_SVG_HANDLE.clone(),
+                };
+                let svg_bounds = Rectangle {
+                    x: cross_bounds.center_x() - icon_size / 2.0,
+                    y: cross_bounds.center_y() - icon_size / 2.0,
+                    width: icon_size,
+                    height: icon_size,
+                };
+                renderer.draw_svg(
+                    svg::Svg::new(handle).color(icon_color),
+                    svg_bounds,
+                    cross_bounds,
+                );
+                None
+            }
+            #[cfg(not(feature = "svg"))]
+            CloseIconSource::Svg | CloseIconSource::CustomSvg(_) => {
+                Some((CODICON_CLOSE, CODICON_FONT))
+            }
+            CloseIconSource::Font(glyph, font) => Some((*glyph, *font)),
+        };
+
+        if let Some((glyph, font)) = glyph {
+            renderer.fill_text(
+                iced::advanced::text::Text {
+                    content: glyph.to_string(),
+                    bounds: Size::new(cross_bounds.width, cross_bounds.height),
+                    size: Pixels(icon_size),
+                    font,
+                    align_x: text::Alignment::Center,
+                    align_y: Vertical::Center,
+                    line_height: LineHeight::Relative(1.0),
+                    shaping: text::Shaping::Auto,
+                    wrapping: Wrapping::default(),
+                },
+                Point::new(cross_bounds.center_x(), cross_bounds.center_y()),
+                icon_color,
+                cross_bounds,
             );
         }
     }
 }
 
+/// What a [`TooltipOverlay`] shows.
+pub(crate) enum TooltipBody<'a, Message, Theme, Renderer> {
+    /// Text drawn with the tooltip style.
+    Text(&'a str),
+    /// A custom element and its widget tree.
+    Element(Element<'a, Message, Theme, Renderer>, Tree),
+}
+
+impl<'a, Message, Theme, Renderer> TooltipBody<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Wraps `element` with a fresh widget tree.
+    pub fn element(element: Element<'a, Message, Theme, Renderer>) -> Self {
+        let tree = Tree::new(element.as_widget());
+        Self::Element(element, tree)
+    }
+}
+
 /// A floating tooltip overlay rendered above all other content.
-pub(crate) struct TooltipOverlay<'a, Renderer>
+pub(crate) struct TooltipOverlay<'a, Message, Theme, Renderer>
 where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
 {
-    pub text: &'a str,
+    pub body: TooltipBody<'a, Message, Theme, Renderer>,
     pub position: Point,
     pub style: TooltipStyle,
     pub text_size: f32,
     pub font: Font,
-    _renderer: PhantomData<Renderer>,
+    /// Measurement reused across frames while the text, size, font and
+    /// available width are unchanged.
+    measurement: &'a mut Option<TooltipMeasurement>,
+    /// The y coordinate the tooltip's bottom edge moves to when it doesn't
+    /// fit below `position`.
+    flip_y: f32,
+    /// Whether the tooltip is always centered on `position.x`.
+    centered: bool,
+    /// Horizontal offset of the pointer tip from the node's left edge (set in `layout`).
+    pointer_x: f32,
+    /// Whether the tooltip was flipped above the cursor (set in `layout`).
+    flipped: bool,
 }
 
-impl<'a, Renderer> TooltipOverlay<'a, Renderer>
+impl<'a, Message, Theme, Renderer> TooltipOverlay<'a, Message, Theme, Renderer>
 where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
 {
     pub fn new(
-        text: &'a str,
+        body: TooltipBody<'a, Message, Theme, Renderer>,
         position: Point,
         style: TooltipStyle,
         text_size: f32,
         font: Font,
+        measurement: &'a mut Option<TooltipMeasurement>,
     ) -> Self {
         Self {
-            text,
+            body,
             position,
             style,
             text_size,
             font,
-            _renderer: PhantomData,
+            measurement,
+            flip_y: position.y - 4.0,
+            centered: false,
+            pointer_x: 0.0,
+            flipped: false,
+        }
+    }
+
+    /// Centers the tooltip `gap` below `bounds`, or `gap` above them if it
+    /// doesn't fit below.
+    pub fn anchored_to(mut self, bounds: Rectangle, gap: f32) -> Self {
+        self.position = Point::new(bounds.center_x(), bounds.y + bounds.height + gap);
+        self.flip_y = bounds.y - gap;
+        self.centered = true;
+        self
+    }
+
+    /// The height reserved for the pointer triangle, or `0.0` when disabled.
+    fn pointer_height(&self) -> f32 {
+        if self.style.pointer {
+            self.style.pointer_size.max(0.0)
+        } else {
+            0.0
         }
     }
 }
 
-impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for TooltipOverlay<'_, Renderer>
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for TooltipOverlay<'_, Message, Theme, Renderer>
 where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
 {
-    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
         use iced::advanced::text::Paragraph;
 
         let padding = self.style.padding;
-
-        // Measure the tooltip text to determine the node size.
-        let paragraph = <Renderer as iced::advanced::text::Renderer>::Paragraph::with_text(
-            iced::advanced::text::Text {
-                content: self.text,
-                bounds: Size::new(bounds.width * 0.5, f32::INFINITY),
-                size: Pixels(self.text_size),
-                font: self.font,
-                align_x: text::Alignment::Left,
-                align_y: Vertical::Top,
-                line_height: iced::advanced::widget::text::LineHeight::Relative(1.3),
-                shaping: text::Shaping::Auto,
-                wrapping: iced::advanced::widget::text::Wrapping::default(),
+        let max_width = self
+            .style
+            .max_width
+            .unwrap_or(bounds.width * 0.5)
+            .min(bounds.width - padding.left - padding.right)
+            .max(0.0);
+
+        let (content_size, element_node) = match &mut self.body {
+            TooltipBody::Element(element, tree) => {
+                let limits = Limits::new(Size::ZERO, Size::new(max_width, bounds.height));
+                let node = element.as_widget_mut().layout(tree, renderer, &limits);
+                (node.size(), Some(node))
+            }
+            TooltipBody::Text(content) => match self.measurement.as_ref() {
+                Some(m)
+                    if m.text == *content
+                        && m.text_size == self.text_size
+                        && m.font == self.font
+                        && m.max_width == max_width =>
+                {
+                    (m.size, None)
+                }
+                _ => {
+                    // Measure the tooltip text to determine the node size.
+                    // Explicit line breaks in the content are preserved by the
+                    // paragraph.
+                    let paragraph =
+                        <Renderer as iced::advanced::text::Renderer>::Paragraph::with_text(
+                            iced::advanced::text::Text {
+                                content: *content,
+                                bounds: Size::new(max_width, f32::INFINITY),
+                                size: Pixels(self.text_size),
+                                font: self.font,
+                                align_x: text::Alignment::Left,
+                                align_y: Vertical::Top,
+                                line_height: iced::advanced::widget::text::LineHeight::Relative(
+                                    1.3,
+                                ),
+                                shaping: text::Shaping::Auto,
+                                wrapping: iced::advanced::widget::text::Wrapping::default(),
+                            },
+                        );
+                    let size = paragraph.min_bounds();
+
+                    *self.measurement = Some(TooltipMeasurement {
+                        text: (*content).to_owned(),
+                        text_size: self.text_size,
+                        font: self.font,
+                        max_width,
+                        size,
+                    });
+                    (size, None)
+                }
             },
-        );
-
-        let text_size = paragraph.min_bounds();
-        let node_width = text_size.width + padding.left + padding.right;
-        let node_height = text_size.height + padding.top + padding.bottom;
-
-        let mut x = self.position.x;
+        };
+        let pointer = self.pointer_height();
+        let node_width = content_size.width + padding.left + padding.right;
+        let node_height = content_size.height + padding.top + padding.bottom + pointer;
+
+        // With a pointer, center the tooltip on the cursor so the tip lines up.
+        let mut x = if self.style.pointer || self.centered {
+            self.position.x - node_width / 2.0
+        } else {
+            self.position.x
+        };
         let mut y = self.position.y;
 
         // Clamp to stay within window bounds.
         if x + node_width > bounds.width {
             x = (bounds.width - node_width).max(0.0);
         }
-        if y + node_height > bounds.height {
-            // Show above cursor instead.
-            y = (self.position.y - node_height - 4.0).max(0.0);
+        x = x.max(0.0);
+        self.flipped = y + node_height > bounds.height;
+        if self.flipped {
+            // Show above the anchor instead.
+            y = (self.flip_y - node_height).max(0.0);
         }
 
-        let mut node = Node::new(Size::new(node_width, node_height));
+        // Keep the pointer base inside the tooltip's straight edge.
+        let inset = pointer + self.style.border_radius.top_left.max(0.0);
+        self.pointer_x = if node_width > 2.0 * inset {
+            (self.position.x - x).clamp(inset, node_width - inset)
+        } else {
+            node_width / 2.0
+        };
+
+        // The pointer strip sits above the body unless flipped.
+        let size = Size::new(node_width, node_height);
+        let mut node = match element_node {
+            Some(child) => {
+                let top = if self.flipped { 0.0 } else { pointer };
+                Node::with_children(
+                    size,
+                    vec![child.move_to(Point::new(padding.left, top + padding.top))],
+                )
+            }
+            None => Node::new(size),
+        };
         node.move_to_mut(Point::new(x, y));
         node
     }
@@ -1062,16 +3602,54 @@ where
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _theme: &Theme,
+        theme: &Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
     ) {
         use iced::advanced::widget::text::{LineHeight, Wrapping};
 
-        let bounds = layout.bounds();
+        let pointer = self.pointer_height();
+        let node_bounds = layout.bounds();
         let padding = self.style.padding;
 
+        // The pointer occupies a strip above the body, or below it when flipped.
+        let bounds = Rectangle {
+            y: if self.flipped {
+                node_bounds.y
+            } else {
+                node_bounds.y + pointer
+            },
+            height: node_bounds.height - pointer,
+            ..node_bounds
+        };
+
+        // Draw the pointer as a stack of one-pixel rows narrowing toward the tip.
+        if pointer > 0.0 {
+            let rows = pointer.ceil() as usize;
+            let tip_x = node_bounds.x + self.pointer_x;
+            for row in 0..rows {
+                let half_width = (row + 1) as f32;
+                let row_y = if self.flipped {
+                    bounds.y + bounds.height + (rows - 1 - row) as f32
+                } else {
+                    node_bounds.y + pointer - rows as f32 + row as f32
+                };
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: tip_x - half_width,
+                            y: row_y,
+                            width: half_width * 2.0,
+                            height: 1.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    self.style.background,
+                );
+            }
+        }
+
         // Draw background.
         renderer.fill_quad(
             renderer::Quad {
@@ -1086,6 +3664,26 @@ where
             self.style.background,
         );
 
+        let content = match &self.body {
+            TooltipBody::Text(content) => *content,
+            TooltipBody::Element(element, tree) => {
+                if let Some(child) = layout.children().next() {
+                    element.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: self.style.text_color,
+                        },
+                        child,
+                        cursor,
+                        &node_bounds,
+                    );
+                }
+                return;
+            }
+        };
+
         // Draw text.
         let text_bounds = Rectangle {
             x: bounds.x + padding.left,
@@ -1096,17 +3694,17 @@ where
 
         renderer.fill_text(
             iced::advanced::text::Text {
-                content: self.text.to_string(),
+                content: content.to_string(),
                 bounds: Size::new(text_bounds.width, text_bounds.height),
                 size: Pixels(self.text_size),
                 font: self.font,
                 align_x: text::Alignment::Left,
-                align_y: Vertical::Center,
+                align_y: Vertical::Top,
                 line_height: LineHeight::Relative(1.3),
                 shaping: text::Shaping::Auto,
                 wrapping: Wrapping::default(),
             },
-            Point::new(text_bounds.x, text_bounds.center_y()),
+            Point::new(text_bounds.x, text_bounds.y),
             self.style.text_color,
             text_bounds,
         );
@@ -1116,20 +3714,23 @@ where
 /// A floating overlay that renders the dragged tab above all other content.
 ///
 /// This overlay escapes the scrollable's clip region, ensuring the dragged tab
-/// is never clipped. Its Y position is locked to the tab bar's row while X
-/// follows the cursor.
+/// is never clipped. It is placed at `position`, which the
+/// [`TabBar`](super::TabBar) keeps within its `drag_lift` band of the row.
 pub(crate) struct DragTabOverlay<'a, 'b, Theme, Renderer>
 where
     Theme: Catalog,
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
 {
-    pub tab_label: TabLabel,
+    pub tab_label: &'a TabLabel,
     pub position: Point,
     pub tab_size: Size,
     pub class: &'a <Theme as Catalog>::Class<'b>,
     pub icon_data: (Font, f32),
     pub text_data: (Font, f32),
     pub close_size: f32,
+    pub close_hit_area: f32,
+    pub layout_size_offset: f32,
+    pub close_icon: &'a CloseIconSource,
     pub close_spacing: f32,
     pub icon_spacing: f32,
     pub padding: Padding,
@@ -1137,6 +3738,12 @@ where
     pub height: Length,
     pub has_close: bool,
     pub icon_position: Position,
+    pub overlay_style: DragOverlayStyle,
+    pub background: Option<Background>,
+    pub close_color: Option<Color>,
+    /// Position of the dragged tab in the bar, and the number of tabs.
+    pub index: usize,
+    pub total: usize,
     _renderer: PhantomData<Renderer>,
 }
 
@@ -1147,13 +3754,16 @@ where
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
-        tab_label: TabLabel,
+        tab_label: &'a TabLabel,
         position: Point,
         tab_size: Size,
         class: &'a <Theme as Catalog>::Class<'b>,
         icon_data: (Font, f32),
         text_data: (Font, f32),
         close_size: f32,
+        close_hit_area: f32,
+        layout_size_offset: f32,
+        close_icon: &'a CloseIconSource,
         close_spacing: f32,
         icon_spacing: f32,
         padding: Padding,
@@ -1161,6 +3771,11 @@ where
         height: Length,
         has_close: bool,
         icon_position: Position,
+        overlay_style: DragOverlayStyle,
+        background: Option<Background>,
+        close_color: Option<Color>,
+        index: usize,
+        total: usize,
     ) -> Self {
         Self {
             tab_label,
@@ -1170,6 +3785,9 @@ where
             icon_data,
             text_data,
             close_size,
+            close_hit_area,
+            layout_size_offset,
+            close_icon,
             close_spacing,
             icon_spacing,
             padding,
@@ -1177,6 +3795,11 @@ where
             height,
             has_close,
             icon_position,
+            overlay_style,
+            background,
+            close_color,
+            index,
+            total,
             _renderer: PhantomData,
         }
     }
@@ -1186,15 +3809,17 @@ impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
     for DragTabOverlay<'_, '_, Theme, Renderer>
 where
     Theme: Catalog + text::Catalog + container::Catalog,
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font> + svg::Renderer,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font> + CloseIconRenderer,
 {
     fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
         let label_row: Row<'_, Message, Theme, Renderer> =
             build_single_tab_row::<Message, Theme, Renderer>(
-                &self.tab_label,
+                self.tab_label,
                 self.icon_data.1,
                 self.text_data.1,
                 self.close_size,
+                self.close_hit_area,
+                self.layout_size_offset,
                 self.close_spacing,
                 self.icon_spacing,
                 self.padding,
@@ -1204,6 +3829,7 @@ where
                 self.icon_position,
                 Some(self.icon_data.0),
                 Some(self.text_data.0),
+                None,
             );
 
         let mut element: Element<'_, Message, Theme, Renderer> = label_row.into();
@@ -1212,11 +3838,12 @@ where
         let limits = Limits::new(Size::ZERO, self.tab_size);
         let mut node = element.as_widget_mut().layout(&mut tree, renderer, &limits);
 
-        // Clamp X so the tab stays within window bounds.
-        let x = self
-            .position
-            .x
-            .clamp(0.0, (bounds.width - self.tab_size.width).max(0.0));
+        // Clamp X so the (scaled) tab stays within window bounds.
+        let overhang = (self.tab_size.width * (self.overlay_style.scale - 1.0) / 2.0).max(0.0);
+        let x = self.position.x.clamp(
+            overhang,
+            (bounds.width - self.tab_size.width - overhang).max(overhang),
+        );
         let y = self.position.y;
 
         node.move_to_mut(Point::new(x, y));
@@ -1239,9 +3866,106 @@ where
             icon_data: self.icon_data,
             text_data: self.text_data,
             close_size: self.close_size,
+            close_icon: self.close_icon,
+            opacity: self.overlay_style.opacity,
+            filter: None,
+            hover_fade: None,
+            truncate: false,
             viewport: &viewport,
+            index: self.index,
+            total: self.total,
+            close_color: self.close_color,
         };
         let dragged_status = (Some(Status::Dragging), None);
-        draw_tab(renderer, &self.tab_label, &dragged_status, layout, &ctx);
+
+        let scale = self.overlay_style.scale;
+        if (scale - 1.0).abs() < f32::EPSILON {
+            draw_tab(
+                renderer,
+                self.tab_label,
+                &dragged_status,
+                self.background,
+                layout,
+                &ctx,
+            );
+        } else {
+            // Scale around the tab's center so it appears "lifted" in place.
+            let center = viewport.center();
+            let transformation = Transformation::translate(center.x, center.y)
+                * Transformation::scale(scale)
+                * Transformation::translate(-center.x, -center.y);
+            renderer.with_transformation(transformation, |renderer| {
+                draw_tab(
+                    renderer,
+                    self.tab_label,
+                    &dragged_status,
+                    self.background,
+                    layout,
+                    &ctx,
+                );
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lays out tabs of the given widths in a row, `spacing` apart.
+    fn row(widths: &[f32], spacing: f32) -> Vec<Node> {
+        let mut x = 0.0;
+        widths
+            .iter()
+            .map(|&width| {
+                let node = Node::new(Size::new(width, 30.0)).move_to(Point::new(x, 0.0));
+                x += width + spacing;
+                node
+            })
+            .collect()
+    }
+
+    fn layouts(nodes: &[Node]) -> Vec<Layout<'_>> {
+        nodes.iter().map(Layout::new).collect()
+    }
+
+    #[test]
+    fn drop_index_flips_in_the_middle_of_each_gap() {
+        // Tabs span 0..100, 120..220 and 240..340, so the gaps are centered
+        // on 110 and 230.
+        let nodes = row(&[100.0, 100.0, 100.0], 20.0);
+        let tabs = layouts(&nodes);
+
+        for (cursor_x, expected) in [
+            (-10.0, 0),
+            (50.0, 0),
+            (105.0, 0),
+            (109.0, 0),
+            (111.0, 1),
+            (170.0, 1),
+            (229.0, 1),
+            (231.0, 2),
+            (300.0, 2),
+            (400.0, 2),
+        ] {
+            assert_eq!(
+                compute_drop_index(&tabs, cursor_x),
+                expected,
+                "cursor at {cursor_x}"
+            );
+        }
+    }
+
+    #[test]
+    fn drop_index_splits_gaps_between_tabs_of_different_widths() {
+        // Tabs span 0..40, 80..280 and 320..360: the boundaries are the
+        // middles of the gaps, not halfway between the tabs' centers.
+        let nodes = row(&[40.0, 200.0, 40.0], 40.0);
+        let tabs = layouts(&nodes);
+
+        assert_eq!(compute_drop_index(&tabs, 59.0), 0);
+        assert_eq!(compute_drop_index(&tabs, 61.0), 1);
+        assert_eq!(compute_drop_index(&tabs, 299.0), 1);
+        assert_eq!(compute_drop_index(&tabs, 301.0), 2);
     }
 }
@@ -6,21 +6,34 @@ use iced::advanced::{
     Clipboard, Layout, Shell, Widget,
     layout::{Limits, Node},
     mouse, overlay, renderer,
-    widget::{Operation, Tree, tree},
+    widget::{
+        Id, Operation, Tree, operation,
+        operation::Outcome,
+        operation::scrollable::{AbsoluteOffset, scroll_by},
+        tree,
+    },
 };
-use iced::widget::{Scrollable, container, scrollable, text};
+use iced::widget::{Container, Row, Scrollable, Space, container, scrollable, text};
 use iced::{
-    Border, Color, Element, Event, Font, Length, Padding, Pixels, Point, Rectangle, Size, Vector,
+    Alignment, Background, Border, Color, Element, Event, Font, Length, Padding, Pixels, Point,
+    Rectangle, Size, Vector, keyboard, touch,
 };
 
-use crate::style::{Catalog, Style};
-use crate::tab::{DragTabOverlay, TabLabel, TooltipOverlay};
+use crate::style::{Catalog, DragOverlayStyle, Style, StyleArgs};
+use crate::tab::{DragTabOverlay, TabLabel, TooltipBody, TooltipOverlay};
 use crate::{Status, StyleFn, tab};
+use iced::advanced::svg;
+use iced::alignment::Vertical;
 use iced::mouse::Cursor;
+use iced_fonts::CODICON_FONT;
+use std::any::Any;
+use std::cell::{Ref, RefCell, RefMut};
 use std::fmt;
 use std::marker::PhantomData;
+use std::ops::Range;
+use std::rc::Rc;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 const DEFAULT_ICON_SIZE: f32 = 16.0;
 const DEFAULT_TEXT_SIZE: f32 = 16.0;
@@ -28,14 +41,27 @@ const DEFAULT_CLOSE_SIZE: f32 = 16.0;
 const DEFAULT_PADDING: Padding = Padding::new(5.0);
 const DEFAULT_SPACING: Pixels = Pixels::ZERO;
 const DEFAULT_CLOSE_SPACING: f32 = 4.0;
+/// Default size of the close button's hit area relative to the icon.
+const DEFAULT_CLOSE_HIT_AREA: f32 = 1.3;
+/// Default offset added to icon/text sizes during layout to prevent clipping.
+const DEFAULT_LAYOUT_SIZE_OFFSET: f32 = 1.0;
 const DEFAULT_ICON_SPACING: f32 = 0.0;
 const DEFAULT_DRAG_THRESHOLD: f32 = 5.0;
+/// Default long-press duration before a touch press can start a reorder.
+const DEFAULT_TOUCH_REORDER_DELAY_MS: u64 = 350;
 /// The default spacing for the scrollbar below the tabs (when not floating).
 const DEFAULT_SCROLLBAR_SPACING: Pixels = Pixels(4.0);
 /// Factor to convert vertical scroll lines to horizontal pixels (matches iced's scroll speed).
-const VERTICAL_TO_HORIZONTAL_SCROLL_FACTOR: f32 = 60.0;
+const DEFAULT_WHEEL_FACTOR: f32 = 60.0;
+/// Default id of the [`Scrollable`] holding the tabs, targeted by page scrolling.
+const SCROLLABLE_ID: &str = "iced_tabs::scrollable";
 /// Default delay before a tooltip appears (in milliseconds).
 const DEFAULT_TOOLTIP_DELAY_MS: u64 = 500;
+/// How long a [`ScrollMode::FloatingAutoHide`] scrollbar stays visible after
+/// the strip is scrolled.
+const SCROLLBAR_AUTO_HIDE_DELAY: Duration = Duration::from_millis(800);
+/// The codicon "close-all" glyph drawn on the close-all button.
+const CODICON_CLOSE_ALL: char = '\u{eac1}';
 
 /// State for the `TabBar` widget tree (used for diff tag).
 #[allow(missing_docs)]
@@ -73,29 +99,104 @@ where
     Theme: Catalog,
     TabId: Eq + Clone,
 {
-    /// The index of the currently active tab.
-    active_tab: usize,
+    /// The index of the currently active tab, used while `active_id` is unset
+    /// or names no tab. `None` when no tab is active.
+    active_tab: Option<usize>,
+    /// The id of the currently active tab, resolved against the current tab
+    /// order whenever the active index is needed.
+    active_id: Option<TabId>,
     /// The vector containing the labels of the tabs.
     tab_labels: Vec<TabLabel>,
     /// The vector containing the indices of the tabs.
     tab_indices: Vec<TabId>,
     /// Per-tab status and close-button hover state.
     tab_statuses: Vec<(Option<Status>, Option<bool>)>,
-    /// Optional tooltip text for each tab (parallel to `tab_labels`).
-    tab_tooltips: Vec<Option<String>>,
+    /// Optional tooltip for each tab (parallel to `tab_labels`).
+    tab_tooltips: Vec<Option<TooltipContent<'a, Message, Theme, Renderer>>>,
+    /// Optional human-readable name for each tab (parallel to `tab_labels`).
+    tab_names: Vec<Option<String>>,
+    /// Optional background override for each tab (parallel to `tab_labels`).
+    tab_backgrounds: Vec<Option<Background>>,
+    /// Optional close icon color override for each tab (parallel to
+    /// `tab_labels`).
+    tab_close_colors: Vec<Option<Color>>,
+    /// Whether each tab is locked in place (parallel to `tab_labels`).
+    tab_locked: Vec<bool>,
+    /// Section headers, each with the index of the tab it precedes.
+    sections: Vec<(usize, String)>,
+    /// The number of tabs at the end of the per-tab vectors that are pinned
+    /// to the right edge of the bar.
+    trailing_count: usize,
+    /// Optional tooltip text shown when hovering a tab's close button.
+    close_tooltip: Option<String>,
+    /// Whether tabs without a tooltip show their name or text label instead.
+    auto_tooltip: bool,
+    /// Where tooltips are placed.
+    tooltip_anchor: TooltipAnchor,
+    /// Id of the inner [`Scrollable`].
+    id: Option<Id>,
+    /// Text that tab labels are matched against.
+    filter: Option<String>,
     /// The function that produces the message when a tab is selected.
-    on_select: Arc<dyn Fn(TabId) -> Message>,
+    /// Takes `(index, id)`.
+    on_select: Arc<dyn Fn(usize, TabId) -> Message>,
     /// The function that produces the message when the close icon was pressed.
-    on_close: Option<Arc<dyn Fn(TabId) -> Message>>,
+    /// Takes `(index, id)`.
+    on_close: Option<Arc<dyn Fn(usize, TabId) -> Message>>,
+    /// Whether closing the active tab also selects its neighbor.
+    select_neighbor_on_close: bool,
+    /// The function that produces the message when closing a tab is requested.
+    on_close_request: Option<Arc<dyn Fn(usize, TabId) -> Message>>,
     /// The function that produces the message when a tab is dragged to a new position.
     /// Takes `(from_index, to_index)`.
     on_reorder: Option<Arc<dyn Fn(usize, usize) -> Message>>,
+    /// The function that produces the message with the full tab order after a reorder.
+    on_reorder_ids: Option<Arc<dyn Fn(Vec<TabId>) -> Message>>,
+    /// The function that produces the message when a tab is moved, with the
+    /// id of the tab and its new index.
+    on_move: Option<Arc<dyn Fn(TabId, usize) -> Message>>,
+    /// The function that produces the message with a tab's window-space bounds.
+    on_tab_bounds: Option<Arc<dyn Fn(TabId, Rectangle) -> Message>>,
+    /// The function that produces the message when the tabs start or stop overflowing.
+    on_overflow: Option<Arc<dyn Fn(bool) -> Message>>,
+    /// The function that produces the message when the tabs are scrolled to either end.
+    on_scroll_edge: Option<Arc<dyn Fn(ScrollEdge) -> Message>>,
+    /// The function that produces the message when the tab strip is double-clicked.
+    on_double_click: Option<Arc<dyn Fn(Option<TabId>) -> Message>>,
+    /// The function that produces the message when the empty strip area is double-clicked.
+    on_new_tab: Option<Arc<dyn Fn() -> Message>>,
+    /// The function that produces the message when a reorder drag starts.
+    on_drag_start: Option<Arc<dyn Fn(TabId) -> Message>>,
+    /// The function that produces the message when a reorder drag ends.
+    on_drag_end: Option<Arc<dyn Fn() -> Message>>,
+    /// The function that produces the message when a dragged tab moves.
+    on_drag_move: Option<Arc<dyn Fn(TabId, Point) -> Message>>,
+    /// The function that produces the message when a tab is dropped onto another bar.
+    on_drag_out: Option<Arc<dyn Fn(TabId) -> Message>>,
+    /// The function that produces the message when a tab from another bar is dropped here.
+    on_accept_external: Option<Arc<dyn Fn(TabId, usize) -> Message>>,
+    /// The function that produces the message when the tentative drop index changes.
+    on_reorder_preview: Option<Arc<dyn Fn(usize, usize) -> Message>>,
+    /// The function that produces the message when a tab is renamed in place.
+    on_rename: Option<Arc<dyn Fn(TabId, String) -> Message>>,
+    /// The function that produces the message when the other tabs should be closed.
+    on_close_others: Option<Arc<dyn Fn(TabId) -> Message>>,
+    /// The function that produces the message when the edge of a tab is clicked.
+    on_edge_click: Option<Arc<dyn Fn(TabId) -> Message>>,
+    /// The function that produces the message when the last tab is closed.
+    on_empty: Option<Arc<dyn Fn() -> Message>>,
+    /// The function that produces the message when the close-all button is clicked.
+    on_close_all: Option<Arc<dyn Fn() -> Message>>,
     /// The width of the [`TabBar`].
     width: Length,
     /// The height of the [`TabBar`].
     height: Length,
+    /// Whether the bar grows past `height` to fit its tallest tab.
+    auto_height: bool,
     /// The maximum height of the [`TabBar`].
     max_height: f32,
+    /// The maximum width of the [`TabBar`].
+    max_width: f32,
     /// Optional fixed width for each tab. When `None`, tabs auto-size to content.
     tab_width: Option<f32>,
     /// The icon size.
@@ -104,14 +205,24 @@ where
     text_size: f32,
     /// The size of the close icon.
     close_size: f32,
+    /// Size of the close button's clickable area relative to `close_size`.
+    close_hit_area: f32,
+    /// Extra size given to icons, text and the close icon during layout.
+    layout_size_offset: f32,
     /// The padding of the tabs of the [`TabBar`].
     padding: Padding,
+    /// The padding between the edge of the [`TabBar`] and its tabs.
+    bar_padding: Padding,
     /// The spacing of the tabs of the [`TabBar`].
     spacing: Pixels,
     /// Spacing between a tab's label content and its close button.
     close_spacing: f32,
-    /// Spacing between the icon and text in [`TabLabel::IconText`] mode.
+    /// Spacing between the icon and text in [`TabLabel::IconText`] mode when
+    /// they sit side by side ([`Position::Left`] and [`Position::Right`]).
     icon_spacing: f32,
+    /// Spacing between the icon and text in [`TabLabel::IconText`] mode when
+    /// they are stacked ([`Position::Top`] and [`Position::Bottom`]).
+    vertical_icon_spacing: f32,
     /// The optional icon font of the [`TabBar`].
     font: Option<Font>,
     /// The optional text font of the [`TabBar`].
@@ -120,17 +231,64 @@ where
     class: <Theme as Catalog>::Class<'a>,
     /// Where the icon is placed relative to text
     position: Position,
-    /// Minimum mouse movement (in pixels) before a press is considered a drag.
-    drag_threshold: f32,
+    /// Minimum horizontal movement (in pixels) before a press starts a reorder.
+    reorder_threshold: f32,
+    /// Minimum vertical movement (in pixels) before a press starts a drag, and
+    /// how far past the strip a dragged tab must be pulled to detach it.
+    detach_threshold: f32,
+    /// How long a finger must stay down before a touch press can start a drag.
+    touch_reorder_delay: Duration,
+    /// Whether tabs are selected when the press on them is released.
+    select_on_release: bool,
+    /// What clicking the active tab does.
+    reselect_behavior: ReselectBehavior<Message, TabId>,
+    /// Cursor shown while a tab is being dragged.
+    drag_cursor: mouse::Interaction,
+    /// Whether a dragged tab follows the cursor in an overlay.
+    drag_overlay: bool,
+    /// Opacity and scale of the tab that follows the cursor while dragging.
+    drag_overlay_style: DragOverlayStyle,
+    /// How far the dragged tab may follow the cursor above or below its row.
+    drag_lift: f32,
+    /// Whether the bar is drawn with its hovered style while the cursor is
+    /// over it.
+    bar_hover: bool,
+    /// How long a closed tab takes to fade out. Zero disables the animation.
+    close_animation: Duration,
+    /// How long the hover background takes to fade in and out. Zero switches
+    /// instantly.
+    hover_animation: Duration,
+    /// How long the active tab indicator takes to slide to a newly selected tab.
+    indicator_animation: Duration,
+    /// How tabs that don't fit the bar are handled.
+    overflow_behavior: OverflowBehavior,
+    /// Whether Ctrl+W closes the focused tab.
+    keyboard_close: bool,
+    /// Whether bare Delete and Backspace close the focused tab too.
+    close_on_delete: bool,
+    /// Where the close icon comes from.
+    close_icon: CloseIconSource,
     /// Scroll behavior and scrollbar visibility for the tab bar.
     scroll_mode: ScrollMode,
+    /// Channel shared with other bars that tabs can be dragged into.
+    drag_channel: Option<TabDragChannel<TabId>>,
+    /// Where the tabs sit in the bar when they don't fill it.
+    tab_alignment: Alignment,
+    /// Whether the tabs are shown in reverse order.
+    reversed: bool,
+    /// How far the wheel and the keyboard scroll the tabs.
+    scroll_config: ScrollConfig,
+    /// The size of the scrollbar.
+    scrollbar_style: ScrollbarStyle,
+    /// Whether the strip settles on a tab edge after scrolling.
+    scroll_snap: bool,
     /// Delay before a tooltip appears when hovering a tab.
     tooltip_delay: Duration,
     _renderer: PhantomData<Renderer>,
 }
 
 /// Icon position relative to text. Only meaningful when using [`TabLabel::IconText`].
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub enum Position {
     /// Icon is placed above the text.
     Top,
@@ -155,6 +313,81 @@ impl Position {
     }
 }
 
+/// The tab order and active tab of a [`TabBar`].
+///
+/// Taken with [`TabBar::snapshot`] and restored with
+/// [`TabBar::from_snapshot`]. With the `serde` feature enabled it can be
+/// serialized to persist the arrangement between runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TabBarSnapshot<TabId> {
+    /// The ids of the tabs, in display order.
+    pub order: Vec<TabId>,
+    /// The index of the active tab within `order`, or `None` if no tab is
+    /// active.
+    pub active: Option<usize>,
+}
+
+/// Lets tabs be dragged from one [`TabBar`] into another.
+///
+/// Create one channel and pass a clone of it to every participating bar with
+/// [`TabBar::drag_channel`]. While a tab is dragged outside its own bar, the
+/// channel carries its id; a bar with
+/// [`on_accept_external`](TabBar::on_accept_external) shows where it would be
+/// inserted. On release over that bar, it publishes `on_accept_external` and the
+/// source bar publishes [`on_drag_out`](TabBar::on_drag_out).
+///
+/// Keep the channel in the application state, so it outlives the bars that
+/// are rebuilt on every `view`.
+pub struct TabDragChannel<TabId>(Rc<RefCell<Option<ExternalDrag<TabId>>>>);
+
+/// A tab dragged outside of its own [`TabBar`].
+#[derive(Debug)]
+pub(crate) struct ExternalDrag<TabId> {
+    /// Id of the dragged tab.
+    pub id: TabId,
+    /// Whether the cursor is over a bar accepting the tab.
+    pub accepted: bool,
+    /// Whether the drag has been released. The entry is kept until the next
+    /// drag so every bar can read it while handling the release.
+    pub released: bool,
+}
+
+impl<TabId> TabDragChannel<TabId> {
+    /// Creates a new, empty channel.
+    pub fn new() -> Self {
+        Self(Rc::new(RefCell::new(None)))
+    }
+
+    pub(crate) fn borrow(&self) -> Ref<'_, Option<ExternalDrag<TabId>>> {
+        self.0.borrow()
+    }
+
+    pub(crate) fn borrow_mut(&self) -> RefMut<'_, Option<ExternalDrag<TabId>>> {
+        self.0.borrow_mut()
+    }
+}
+
+impl<TabId> Clone for TabDragChannel<TabId> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<TabId> Default for TabDragChannel<TabId> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TabId: fmt::Debug> fmt::Debug for TabDragChannel<TabId> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TabDragChannel")
+            .field(&self.0.borrow())
+            .finish()
+    }
+}
+
 /// Scroll behavior of the [`TabBar`].
 ///
 /// This controls how overflowing tabs can be scrolled and how (or if) the
@@ -163,6 +396,9 @@ impl Position {
 pub enum ScrollMode {
     /// Scrollbar overlays the content when visible.
     Floating,
+    /// Like [`Floating`](Self::Floating), but the scrollbar is only shown
+    /// while the cursor is over the bar and shortly after it is scrolled.
+    FloatingAutoHide,
     /// Scrollbar is embedded in its own row below the tabs with the given spacing.
     Below(Pixels),
     /// Scrollbar is hidden; scrolling is only possible via mouse wheel.
@@ -175,6 +411,227 @@ impl Default for ScrollMode {
     }
 }
 
+/// How far the [`TabBar`] scrolls its tabs for each kind of input.
+///
+/// Set with [`TabBar::scroll_config`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollConfig {
+    /// Pixels scrolled per line of a line-based mouse wheel. Pixel-based
+    /// deltas, e.g. from trackpads, are used as they are. Defaults to `60.0`,
+    /// matching iced's scroll speed.
+    pub wheel_factor: f32,
+    /// Pixels scrolled by Page Up/Down while a tab has keyboard focus.
+    /// Defaults to `None`, which scrolls by the visible width of the strip.
+    pub keyboard_step: Option<f32>,
+}
+
+impl Default for ScrollConfig {
+    fn default() -> Self {
+        Self {
+            wheel_factor: DEFAULT_WHEEL_FACTOR,
+            keyboard_step: None,
+        }
+    }
+}
+
+/// The size of the [`TabBar`]'s scrollbar.
+///
+/// Set with [`TabBar::scrollbar_style`]. Whether the scrollbar is shown at
+/// all, and when, is chosen with the [`ScrollMode`]; its colors come from the
+/// theme's `scrollable` style.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollbarStyle {
+    /// Width of the rail. Defaults to `10.0`.
+    pub width: f32,
+    /// Width of the thumb within the rail. Defaults to `10.0`.
+    pub scroller_width: f32,
+    /// Space around the rail. Defaults to `0.0`.
+    pub margin: f32,
+}
+
+impl Default for ScrollbarStyle {
+    fn default() -> Self {
+        Self {
+            width: 10.0,
+            scroller_width: 10.0,
+            margin: 0.0,
+        }
+    }
+}
+
+/// A bundle of sizes for the [`TabBar`], set with [`TabBar::density`].
+///
+/// | Preset        | `padding` (v, h) | `spacing` | `text_size` | `icon_size` | `close_size` | `height`  |
+/// |---------------|------------------|-----------|-------------|-------------|--------------|-----------|
+/// | `Compact`     | 2, 6             | 0         | 13          | 13          | 12           | 24        |
+/// | `Comfortable` | 5, 5             | 0         | 16          | 16          | 16           | `Shrink`  |
+/// | `Spacious`    | 8, 12            | 4         | 18          | 18          | 18           | 44        |
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Density {
+    /// Smaller text and tighter padding, e.g. for tool windows.
+    Compact,
+    /// The [`TabBar`]'s defaults.
+    #[default]
+    Comfortable,
+    /// Larger text and roomier tabs, e.g. for touch screens.
+    Spacious,
+}
+
+/// How the [`TabBar`] handles tabs that don't fit its width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OverflowBehavior {
+    /// The tabs scroll.
+    #[default]
+    Scroll,
+    /// Icon-and-text labels collapse to their icons and, if that is not
+    /// enough, text labels are shortened until all tabs fit. Tabs that still
+    /// don't fit scroll.
+    CollapseToIcons {
+        /// Whether the active tab keeps its full label.
+        keep_active_text: bool,
+    },
+    /// Tabs that don't fit move onto further lines, and the bar grows taller
+    /// to hold them. The bar needs a width other than [`Length::Shrink`] to
+    /// wrap.
+    ///
+    /// Tabs can't be reordered by dragging while they wrap; the keyboard
+    /// shortcuts still move them.
+    Wrap,
+}
+
+/// An end of the [`TabBar`]'s scrolling strip, as reported by
+/// [`TabBar::on_scroll_edge`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScrollEdge {
+    /// The start of the strip, where the first tab is.
+    Start,
+    /// The end of the strip, where the last tab is.
+    End,
+}
+
+/// Where the tooltips of the [`TabBar`] are placed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TooltipAnchor {
+    /// Below the bar, at the cursor.
+    #[default]
+    Cursor,
+    /// Centered below the tab, `gap` pixels away. Flips above the tab when
+    /// there is no room below.
+    Tab {
+        /// Distance between the tab and the tooltip.
+        gap: f32,
+    },
+}
+
+/// The content of a tab's tooltip.
+pub enum TooltipContent<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// Plain text, drawn with the tooltip style.
+    Text(String),
+    /// A custom element, built each time the tooltip is shown and drawn on
+    /// the tooltip background.
+    Element(Arc<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'a>),
+}
+
+impl<Message, Theme, Renderer> TooltipContent<'_, Message, Theme, Renderer> {
+    /// Returns the text of a [`Text`](Self::Text) tooltip.
+    pub(crate) fn text(&self) -> Option<&String> {
+        match self {
+            Self::Text(text) => Some(text),
+            Self::Element(_) => None,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Clone for TooltipContent<'_, Message, Theme, Renderer> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Text(text) => Self::Text(text.clone()),
+            Self::Element(view) => Self::Element(Arc::clone(view)),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> fmt::Debug for TooltipContent<'_, Message, Theme, Renderer> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            Self::Element(_) => f.write_str("Element(..)"),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> From<String> for TooltipContent<'_, Message, Theme, Renderer> {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl<Message, Theme, Renderer> From<&str> for TooltipContent<'_, Message, Theme, Renderer> {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+/// What clicking the tab that is already active does.
+///
+/// Set with [`TabBar::reselect_behavior`].
+#[derive(Default)]
+pub enum ReselectBehavior<Message, TabId> {
+    /// The `on_select` message is published again, as for any other tab.
+    #[default]
+    Emit,
+    /// Nothing is published.
+    Suppress,
+    /// The given message is published instead of `on_select`, e.g. to
+    /// scroll the tab's content back to the top.
+    EmitReselect(Arc<dyn Fn(TabId) -> Message>),
+}
+
+impl<Message, TabId> ReselectBehavior<Message, TabId> {
+    /// Publishes the message produced by `on_reselect` instead of
+    /// `on_select`, without wrapping the closure in an [`Arc`] first.
+    pub fn emit_reselect(on_reselect: impl Fn(TabId) -> Message + 'static) -> Self {
+        Self::EmitReselect(Arc::new(on_reselect))
+    }
+}
+
+impl<Message, TabId> Clone for ReselectBehavior<Message, TabId> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Emit => Self::Emit,
+            Self::Suppress => Self::Suppress,
+            Self::EmitReselect(on_reselect) => Self::EmitReselect(Arc::clone(on_reselect)),
+        }
+    }
+}
+
+impl<Message, TabId> fmt::Debug for ReselectBehavior<Message, TabId> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Emit => f.write_str("Emit"),
+            Self::Suppress => f.write_str("Suppress"),
+            Self::EmitReselect(_) => f.write_str("EmitReselect(..)"),
+        }
+    }
+}
+
+/// Where the close icon of the [`TabBar`] comes from.
+///
+/// Only [`Font`](Self::Font) is drawn with plain text rendering. Without the
+/// `svg` feature, [`Svg`](Self::Svg) and [`CustomSvg`](Self::CustomSvg) fall
+/// back to the codicon close glyph, so renderers without SVG support can
+/// still be used.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum CloseIconSource {
+    /// The bundled SVG icon.
+    #[default]
+    Svg,
+    /// A custom SVG icon, tinted with the tab's text color like the bundled one.
+    CustomSvg(svg::Handle),
+    /// A glyph drawn from the given font, e.g. `'\u{ea76}'` from the codicon font.
+    Font(char, Font),
+}
+
 impl<'a, Message, TabId, Theme, Renderer> fmt::Debug for TabBar<'a, Message, TabId, Theme, Renderer>
 where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer,
@@ -188,6 +645,7 @@ where
             .field("size", &self.tab_indices.len())
             .field("width", &self.width)
             .field("height", &self.height)
+            .field("auto_height", &self.auto_height)
             .field("position", &self.position)
             .finish()
     }
@@ -195,11 +653,10 @@ where
 
 impl<'a, Message, TabId, Theme, Renderer> TabBar<'a, Message, TabId, Theme, Renderer>
 where
-    Renderer: renderer::Renderer
-        + iced::advanced::text::Renderer<Font = Font>
-        + iced::advanced::svg::Renderer,
+    Renderer:
+        renderer::Renderer + iced::advanced::text::Renderer<Font = Font> + tab::CloseIconRenderer,
     Theme: Catalog + text::Catalog + scrollable::Catalog + container::Catalog,
-    TabId: Eq + Clone,
+    TabId: 'static + Eq + Clone,
 {
     /// Creates a new empty [`TabBar`].
     ///
@@ -224,36 +681,139 @@ where
     {
         let count = tab_labels.len();
         Self {
-            active_tab: 0,
+            active_tab: Some(0),
+            active_id: None,
             tab_indices: tab_labels.iter().map(|(id, _)| id.clone()).collect(),
             tab_statuses: tab_labels.iter().map(|_| (None, None)).collect(),
             tab_labels: tab_labels.into_iter().map(|(_, label)| label).collect(),
-            on_select: Arc::new(on_select),
+            on_select: Arc::new(move |_, id| on_select(id)),
             on_close: None,
+            select_neighbor_on_close: false,
+            on_close_request: None,
             on_reorder: None,
+            on_reorder_ids: None,
+            on_move: None,
+            on_tab_bounds: None,
+            on_overflow: None,
+            on_scroll_edge: None,
+            on_double_click: None,
+            on_new_tab: None,
+            on_drag_start: None,
+            on_drag_end: None,
+            on_drag_move: None,
+            on_drag_out: None,
+            on_accept_external: None,
+            on_reorder_preview: None,
+            on_rename: None,
+            on_close_others: None,
+            on_edge_click: None,
+            on_empty: None,
+            on_close_all: None,
             width: Length::Fill,
             height: Length::Shrink,
+            auto_height: false,
             max_height: u32::MAX as f32,
+            max_width: u32::MAX as f32,
             tab_width: None,
             icon_size: DEFAULT_ICON_SIZE,
             text_size: DEFAULT_TEXT_SIZE,
             close_size: DEFAULT_CLOSE_SIZE,
+            close_hit_area: DEFAULT_CLOSE_HIT_AREA,
+            layout_size_offset: DEFAULT_LAYOUT_SIZE_OFFSET,
             padding: DEFAULT_PADDING,
+            bar_padding: Padding::ZERO,
             spacing: DEFAULT_SPACING,
             close_spacing: DEFAULT_CLOSE_SPACING,
             icon_spacing: DEFAULT_ICON_SPACING,
+            vertical_icon_spacing: DEFAULT_ICON_SPACING,
             font: None,
             text_font: None,
             class: <Theme as Catalog>::default(),
             position: Position::default(),
-            drag_threshold: DEFAULT_DRAG_THRESHOLD,
+            reorder_threshold: DEFAULT_DRAG_THRESHOLD,
+            detach_threshold: DEFAULT_DRAG_THRESHOLD,
+            touch_reorder_delay: Duration::from_millis(DEFAULT_TOUCH_REORDER_DELAY_MS),
+            select_on_release: false,
+            reselect_behavior: ReselectBehavior::Emit,
+            drag_cursor: mouse::Interaction::Grabbing,
+            drag_overlay: true,
+            drag_overlay_style: DragOverlayStyle::default(),
+            drag_lift: f32::INFINITY,
+            bar_hover: false,
+            close_animation: Duration::ZERO,
+            hover_animation: Duration::ZERO,
+            indicator_animation: Duration::ZERO,
+            overflow_behavior: OverflowBehavior::default(),
+            keyboard_close: true,
+            close_on_delete: false,
+            close_icon: CloseIconSource::default(),
             scroll_mode: ScrollMode::default(),
+            scroll_config: ScrollConfig::default(),
+            scrollbar_style: ScrollbarStyle::default(),
+            scroll_snap: false,
+            tab_alignment: Alignment::Start,
+            reversed: false,
+            drag_channel: None,
             tab_tooltips: vec![None; count],
+            tab_names: vec![None; count],
+            tab_backgrounds: vec![None; count],
+            tab_close_colors: vec![None; count],
+            tab_locked: vec![false; count],
+            sections: Vec::new(),
+            trailing_count: 0,
+            close_tooltip: None,
+            auto_tooltip: false,
+            tooltip_anchor: TooltipAnchor::default(),
+            id: None,
+            filter: None,
             tooltip_delay: Duration::from_millis(DEFAULT_TOOLTIP_DELAY_MS),
             _renderer: PhantomData,
         }
     }
 
+    /// Creates a [`TabBar`] from a [`TabBarSnapshot`].
+    ///
+    /// It expects:
+    /// - The snapshot providing the tab order and the active tab.
+    /// - A function returning the [`TabLabel`] for each id in the snapshot.
+    /// - The function that will be called if a tab is selected by the user.
+    ///   The function receives the id of the selected tab.
+    pub fn from_snapshot<L, F>(
+        snapshot: TabBarSnapshot<TabId>,
+        mut tab_label: L,
+        on_select: F,
+    ) -> Self
+    where
+        L: FnMut(&TabId) -> TabLabel,
+        F: 'static + Fn(TabId) -> Message,
+    {
+        let tab_labels = snapshot
+            .order
+            .into_iter()
+            .map(|id| {
+                let label = tab_label(&id);
+                (id, label)
+            })
+            .collect();
+
+        let mut tab_bar = Self::with_tab_labels(tab_labels, on_select);
+        // A stale snapshot may name a tab that isn't there: leave none active.
+        tab_bar.active_id = snapshot
+            .active
+            .and_then(|active| tab_bar.tab_indices.get(active).cloned());
+        tab_bar.active_tab = None;
+        tab_bar
+    }
+
+    /// Returns the current tab order and active tab of the [`TabBar`].
+    #[must_use]
+    pub fn snapshot(&self) -> TabBarSnapshot<TabId> {
+        TabBarSnapshot {
+            order: self.tab_indices.clone(),
+            active: self.active_index(),
+        }
+    }
+
     /// Sets the size of the close icon of the
     /// [`TabLabel`]s of the [`TabBar`].
     #[must_use]
@@ -262,16 +822,99 @@ where
         self
     }
 
+    /// Sets the size of the close button's clickable area relative to the
+    /// close icon. Defaults to 1.3.
+    ///
+    /// Larger values make the close button easier to hit on touch screens; the
+    /// icon itself keeps its [`close_size`](Self::close_size). The area is part
+    /// of the tab, so it grows the tab rather than reaching into its
+    /// neighbors. Values below 1.0 are treated as 1.0.
+    #[must_use]
+    pub fn close_hit_area(mut self, multiplier: f32) -> Self {
+        self.close_hit_area = multiplier;
+        self
+    }
+
+    /// Sets the extra size, in pixels, reserved around icons and text when
+    /// laying out the tabs. Defaults to 1.0.
+    ///
+    /// Text is measured from its font size, but antialiasing and glyphs that
+    /// overshoot the font's metrics can spill a pixel past those bounds and get
+    /// clipped. Raise this for fonts that still clip at large sizes, or set it
+    /// to zero for pixel-exact alignment with small text.
+    #[must_use]
+    pub fn layout_size_offset(mut self, offset: f32) -> Self {
+        self.layout_size_offset = offset;
+        self
+    }
+
+    /// Sets where the close icon of the [`TabBar`] comes from. Defaults to
+    /// [`CloseIconSource::Svg`].
+    ///
+    /// The widget still requires a [`CloseIconRenderer`](crate::CloseIconRenderer),
+    /// which includes `svg::Renderer` while the `svg` feature is enabled: the
+    /// icon is picked at runtime, so the bound can only be dropped by disabling
+    /// the feature.
+    #[must_use]
+    pub fn close_icon_source(mut self, source: CloseIconSource) -> Self {
+        self.close_icon = source;
+        self
+    }
+
+    /// Sets a custom SVG as the close icon of the [`TabBar`], in place of the
+    /// bundled one.
+    ///
+    /// Shorthand for [`close_icon_source`](Self::close_icon_source) with
+    /// [`CloseIconSource::CustomSvg`].
+    #[must_use]
+    pub fn close_icon_svg(self, handle: svg::Handle) -> Self {
+        self.close_icon_source(CloseIconSource::CustomSvg(handle))
+    }
+
     /// Gets the id of the currently active tab on the [`TabBar`].
     #[must_use]
     pub fn get_active_tab_id(&self) -> Option<&TabId> {
-        self.tab_indices.get(self.active_tab)
+        self.active_index()
+            .and_then(|index| self.tab_indices.get(index))
     }
 
-    /// Gets the index of the currently active tab on the [`TabBar`].
+    /// Gets the index of the currently active tab on the [`TabBar`], or
+    /// `None` if no tab is active.
     #[must_use]
-    pub fn get_active_tab_idx(&self) -> usize {
-        self.active_tab
+    pub fn get_active_tab_idx(&self) -> Option<usize> {
+        self.active_index()
+    }
+
+    /// Gets the label of the tab with the given id on the [`TabBar`].
+    #[must_use]
+    pub fn get_tab_label(&self, id: &TabId) -> Option<&TabLabel> {
+        self.tab_indices
+            .iter()
+            .position(|tab_id| tab_id == id)
+            .map(|index| &self.tab_labels[index])
+    }
+
+    /// Returns the ids of the tabs on the [`TabBar`], in the order they were
+    /// pushed.
+    pub fn tab_ids(&self) -> impl Iterator<Item = &TabId> {
+        self.tab_indices.iter()
+    }
+
+    /// Returns the labels of the tabs on the [`TabBar`], in the order they
+    /// were pushed.
+    pub fn labels(&self) -> impl Iterator<Item = &TabLabel> {
+        self.tab_labels.iter()
+    }
+
+    /// The index of the active tab: the position of `active_id` if it names a
+    /// tab, `active_tab` otherwise, or `None` if neither names a tab.
+    fn active_index(&self) -> Option<usize> {
+        self.active_id
+            .as_ref()
+            .and_then(|active_id| self.tab_indices.iter().position(|id| id == active_id))
+            .or(self
+                .active_tab
+                .filter(|&active_tab| active_tab < self.tab_indices.len()))
     }
 
     /// Gets the height of the [`TabBar`].
@@ -293,6 +936,27 @@ where
         self
     }
 
+    /// Sets whether the [`TabBar`] grows taller than its
+    /// [`height`](Self::height) to fit its tabs. Defaults to `false`.
+    ///
+    /// A fixed height then acts as a minimum, so a [`TabLabel::IconText`]
+    /// stacked vertically with [`Position::Top`] or [`Position::Bottom`] shows
+    /// both its icon and its text instead of being clipped.
+    #[must_use]
+    pub fn auto_height(mut self, auto_height: bool) -> Self {
+        self.auto_height = auto_height;
+        self
+    }
+
+    /// Returns the height the bar is laid out with.
+    fn bar_height(&self) -> Length {
+        if self.auto_height || self.overflow_behavior == OverflowBehavior::Wrap {
+            Length::Shrink
+        } else {
+            self.height
+        }
+    }
+
     /// Sets the font of the icons of the
     /// [`TabLabel`]s of the [`TabBar`].
     #[must_use]
@@ -315,6 +979,16 @@ where
         self
     }
 
+    /// Sets the maximum width of the [`TabBar`].
+    ///
+    /// A bar that would be wider, e.g. one with [`Length::Fill`] in a wide
+    /// window, is capped at this width and placed by its parent's alignment.
+    #[must_use]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
     /// Sets a fixed width for every tab in the [`TabBar`].
     ///
     /// When set, all tabs share the same pixel width regardless of their
@@ -335,18 +1009,82 @@ where
     pub fn on_close<F>(mut self, on_close: F) -> Self
     where
         F: 'static + Fn(TabId) -> Message,
+    {
+        self.on_close = Some(Arc::new(move |_, id| on_close(id)));
+        self
+    }
+
+    /// Sets the message that will be produced when the close icon of a tab
+    /// on the [`TabBar`] is pressed, like [`on_close`](Self::on_close).
+    ///
+    /// The function receives the position of the tab in the bar along with its
+    /// id, which is handy for removing it from a `Vec` in the application.
+    #[must_use]
+    pub fn on_close_indexed<F>(mut self, on_close: F) -> Self
+    where
+        F: 'static + Fn(usize, TabId) -> Message,
     {
         self.on_close = Some(Arc::new(on_close));
         self
     }
 
+    /// Replaces the function that will be called if a tab is selected by the
+    /// user.
+    ///
+    /// Unlike the function given to [`new`](Self::new), it receives the
+    /// position of the tab in the bar along with its id.
+    #[must_use]
+    pub fn on_select_indexed<F>(mut self, on_select: F) -> Self
+    where
+        F: 'static + Fn(usize, TabId) -> Message,
+    {
+        self.on_select = Arc::new(on_select);
+        self
+    }
+
+    /// Sets whether closing the active tab also selects its neighbor.
+    /// Defaults to `false`.
+    ///
+    /// When enabled, clicking the close icon of the active tab publishes the
+    /// [`on_close`](Self::on_close) message followed by the `on_select` message
+    /// of the tab to its right, or to its left if it was the last one. The
+    /// index passed to [`on_select_indexed`](Self::on_select_indexed) is the
+    /// neighbor's position after the closed tab is removed.
+    ///
+    /// Has no effect with [`on_close_request`](Self::on_close_request), since
+    /// the close may still be declined.
+    #[must_use]
+    pub fn select_neighbor_on_close(mut self, select: bool) -> Self {
+        self.select_neighbor_on_close = select;
+        self
+    }
+
+    /// Sets the message that will be produced when the user asks to close a
+    /// tab by pressing its close icon.
+    ///
+    /// Unlike [`on_close`](Self::on_close), this is only a request: the
+    /// application decides whether the tab is actually removed, e.g. after
+    /// confirming unsaved changes. If both are set, `on_close` takes
+    /// precedence.
+    ///
+    /// Setting this enables the drawing of a close icon on the tabs.
+    #[must_use]
+    pub fn on_close_request<F>(mut self, on_close_request: F) -> Self
+    where
+        F: 'static + Fn(TabId) -> Message,
+    {
+        self.on_close_request = Some(Arc::new(move |_, id| on_close_request(id)));
+        self
+    }
+
     /// Sets the message that will be produced when a tab is dragged to a new position.
     ///
     /// The callback receives `(from_index, to_index)` — the original position of
     /// the dragged tab and the position it should be moved to. The consumer is
     /// responsible for reordering their data accordingly.
     ///
-    /// Setting this enables drag-and-drop reordering of tabs.
+    /// Setting this enables drag-and-drop reordering of tabs, as well as
+    /// moving the focused (last clicked) tab with `Ctrl+Shift+Left/Right`.
     #[must_use]
     pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
     where
@@ -356,98 +1094,992 @@ where
         self
     }
 
-    /// Sets the minimum mouse movement (in pixels) before a press is
-    /// considered a drag. Defaults to `5.0`.
+    /// Sets the message that will be produced with the full tab order after a
+    /// tab is dragged to a new position.
     ///
-    /// Only meaningful when [`on_reorder`](Self::on_reorder) is set.
+    /// The callback receives every `TabId` in its new order, so the consumer
+    /// can replace their ordering wholesale instead of applying a
+    /// `(from, to)` move. It can be combined with [`on_reorder`](Self::on_reorder).
+    ///
+    /// Setting this enables drag-and-drop reordering of tabs.
     #[must_use]
-    pub fn drag_threshold(mut self, threshold: f32) -> Self {
-        self.drag_threshold = threshold;
+    pub fn on_reorder_ids<F>(mut self, on_reorder_ids: F) -> Self
+    where
+        F: 'static + Fn(Vec<TabId>) -> Message,
+    {
+        self.on_reorder_ids = Some(Arc::new(on_reorder_ids));
         self
     }
 
-    /// Sets the padding of the tabs of the [`TabBar`].
+    /// Sets the message that will be produced when a tab is moved to a new
+    /// position. The function receives the id of the moved tab and the index
+    /// it moves to, counted as for [`on_reorder`](Self::on_reorder).
+    ///
+    /// The id is recorded when the drag starts and the dragged tab is followed
+    /// to its new index if the application adds or removes tabs during the
+    /// drag, so the message names the tab that was picked up. The drag is
+    /// cancelled if that tab is removed. It can be combined with [`on_reorder`](Self::on_reorder) and
+    /// [`on_reorder_ids`](Self::on_reorder_ids).
+    ///
+    /// Setting this enables drag-and-drop reordering of tabs.
     #[must_use]
-    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
-        self.padding = padding.into();
+    pub fn on_move<F>(mut self, on_move: F) -> Self
+    where
+        F: 'static + Fn(TabId, usize) -> Message,
+    {
+        self.on_move = Some(Arc::new(on_move));
         self
     }
 
-    /// Pushes a [`TabLabel`] to the [`TabBar`].
+    /// Sets the message that will be produced with the window-space bounds of
+    /// each tab, e.g. to anchor a popover to a tab.
+    ///
+    /// The callback fires for every tab once the bar has been laid out, and
+    /// again for any tab whose bounds change afterwards (through scrolling,
+    /// resizing or a change of the tabs). The bounds account for the scroll
+    /// offset of the bar, so tabs scrolled out of view report rectangles that
+    /// lie partly or fully outside the visible area of the bar.
     #[must_use]
-    pub fn push(mut self, id: TabId, tab_label: TabLabel) -> Self {
-        self.tab_labels.push(tab_label);
-        self.tab_indices.push(id);
-        self.tab_statuses.push((None, None));
-        self.tab_tooltips.push(None);
+    pub fn on_tab_bounds<F>(mut self, on_tab_bounds: F) -> Self
+    where
+        F: 'static + Fn(TabId, Rectangle) -> Message,
+    {
+        self.on_tab_bounds = Some(Arc::new(on_tab_bounds));
         self
     }
 
-    /// Pushes a [`TabLabel`] with an associated tooltip to the [`TabBar`].
+    /// Sets the message that will be produced when the tabs start or stop
+    /// overflowing the visible width of the bar, e.g. to show a "more tabs"
+    /// control.
     ///
-    /// The tooltip text will appear after the configured
-    /// [`tooltip_delay`](Self::tooltip_delay) when the cursor hovers over the tab.
+    /// It receives whether the tabs overflow now. It is only sent when that
+    /// changes and reflects the most recent layout of the bar; the first
+    /// layout always reports it.
     #[must_use]
-    pub fn push_with_tooltip(
-        mut self,
-        id: TabId,
-        tab_label: TabLabel,
-        tooltip: impl Into<String>,
-    ) -> Self {
-        self.tab_labels.push(tab_label);
-        self.tab_indices.push(id);
-        self.tab_statuses.push((None, None));
-        self.tab_tooltips.push(Some(tooltip.into()));
+    pub fn on_overflow<F>(mut self, on_overflow: F) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        self.on_overflow = Some(Arc::new(on_overflow));
         self
     }
 
-    /// Gets the amount of tabs on the [`TabBar`].
+    /// Sets the message that will be produced when the tabs are scrolled all
+    /// the way to either end of the bar, e.g. to load more tabs.
+    ///
+    /// It fires once when an end is reached and again only after the strip has
+    /// been scrolled away from it by a few pixels, so it doesn't repeat while the
+    /// strip rests there. Nothing is reported while the tabs fit the bar.
     #[must_use]
-    pub fn size(&self) -> usize {
-        self.tab_indices.len()
+    pub fn on_scroll_edge<F>(mut self, on_scroll_edge: F) -> Self
+    where
+        F: 'static + Fn(ScrollEdge) -> Message,
+    {
+        self.on_scroll_edge = Some(Arc::new(on_scroll_edge));
+        self
     }
 
-    /// Sets the spacing between the tabs of the [`TabBar`].
+    /// Sets the message that will be produced when a tab or the empty area of
+    /// the [`TabBar`] is double-clicked.
+    ///
+    /// The callback receives the id of the double-clicked tab, or `None` if the
+    /// double-click landed on the empty strip area. The second click of a
+    /// double-click on a tab does not select it again or start a drag.
     #[must_use]
-    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
-        self.spacing = spacing.into();
+    pub fn on_double_click<F>(mut self, on_double_click: F) -> Self
+    where
+        F: 'static + Fn(Option<TabId>) -> Message,
+    {
+        self.on_double_click = Some(Arc::new(on_double_click));
         self
     }
 
-    /// Sets the spacing between a tab's label content and its close button.
+    /// Sets the message that will be produced when the empty area after the
+    /// last tab is double-clicked, like in a browser, e.g. to open a new tab.
+    ///
+    /// Double-clicks on tabs, section headers and the scrollbar are not
+    /// affected. It is sent in addition to
+    /// [`on_double_click`](Self::on_double_click) with `None`.
     #[must_use]
-    pub fn close_spacing(mut self, close_spacing: f32) -> Self {
-        self.close_spacing = close_spacing;
+    pub fn on_new_tab<F>(mut self, on_new_tab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_new_tab = Some(Arc::new(on_new_tab));
         self
     }
 
-    /// Sets the spacing between the icon and text in [`TabLabel::IconText`] mode.
+    /// Sets the message that will be produced when the user starts dragging a
+    /// tab to reorder it. The function receives the id of the dragged tab.
     ///
-    /// Has no effect when using [`TabLabel::Icon`] or [`TabLabel::Text`].
+    /// The drag starts once the pointer has moved past the
+    /// [`drag_threshold`](Self::drag_threshold). Only meaningful when
+    /// [`on_reorder`](Self::on_reorder) or [`on_reorder_ids`](Self::on_reorder_ids)
+    /// is set.
     #[must_use]
-    pub fn icon_spacing(mut self, icon_spacing: f32) -> Self {
-        self.icon_spacing = icon_spacing;
+    pub fn on_drag_start<F>(mut self, on_drag_start: F) -> Self
+    where
+        F: 'static + Fn(TabId) -> Message,
+    {
+        self.on_drag_start = Some(Arc::new(on_drag_start));
         self
     }
 
-    /// Sets the scroll behavior of the [`TabBar`].
+    /// Sets the message that will be produced when a drag started by
+    /// [`on_drag_start`](Self::on_drag_start) ends.
     ///
-    /// Use [`ScrollMode::Floating`] for a floating scrollbar,
-    /// [`ScrollMode::Below`] for an always-visible embedded scrollbar,
-    /// or [`ScrollMode::NoScrollbar`] to hide the scrollbar entirely (mouse wheel only).
+    /// It fires on release whether or not the tab moved, and when the drag is
+    /// cancelled, e.g. because the tabs changed underneath it.
     #[must_use]
-    pub fn scroll_mode(mut self, mode: ScrollMode) -> Self {
-        self.scroll_mode = mode;
+    pub fn on_drag_end<F>(mut self, on_drag_end: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_drag_end = Some(Arc::new(on_drag_end));
         self
     }
 
-    /// Sets the delay before a tooltip appears when hovering a tab.
+    /// Sets the message that will be produced when a dragged tab moves. The
+    /// function receives the id of the tab and the top-left corner of the
+    /// dragged tab, in window coordinates.
     ///
-    /// Default: 500 ms. Only affects tabs added with
-    /// [`push_with_tooltip`](Self::push_with_tooltip).
+    /// It fires between [`on_drag_start`](Self::on_drag_start) and
+    /// [`on_drag_end`](Self::on_drag_end) whenever the cursor or finger
+    /// moves, e.g. to highlight drop zones elsewhere in the application.
     #[must_use]
-    pub fn tooltip_delay(mut self, delay: Duration) -> Self {
-        self.tooltip_delay = delay;
+    pub fn on_drag_move<F>(mut self, on_drag_move: F) -> Self
+    where
+        F: 'static + Fn(TabId, Point) -> Message,
+    {
+        self.on_drag_move = Some(Arc::new(on_drag_move));
+        self
+    }
+
+    /// Sets the message that will be produced when a tab of this [`TabBar`] is
+    /// dragged out and dropped onto another bar sharing its
+    /// [`drag_channel`](Self::drag_channel). The function receives the id of the
+    /// tab, which the application should remove from this bar.
+    #[must_use]
+    pub fn on_drag_out<F>(mut self, on_drag_out: F) -> Self
+    where
+        F: 'static + Fn(TabId) -> Message,
+    {
+        self.on_drag_out = Some(Arc::new(on_drag_out));
+        self
+    }
+
+    /// Sets the message that will be produced when a tab dragged from another
+    /// bar sharing this [`TabBar`]'s [`drag_channel`](Self::drag_channel) is dropped
+    /// onto it. The function receives the id of the tab and the index it should
+    /// be inserted at.
+    ///
+    /// Setting this makes the bar show a drop indicator while such a tab hovers
+    /// over it.
+    #[must_use]
+    pub fn on_accept_external<F>(mut self, on_accept_external: F) -> Self
+    where
+        F: 'static + Fn(TabId, usize) -> Message,
+    {
+        self.on_accept_external = Some(Arc::new(on_accept_external));
+        self
+    }
+
+    /// Sets the message that will be produced while a tab is dragged, whenever
+    /// the position it would be dropped at changes.
+    ///
+    /// The callback receives `(from_index, to_index)` like
+    /// [`on_reorder`](Self::on_reorder), which still fires on release. It only
+    /// fires when the target changes, not on every cursor move, and reports
+    /// `(from, from)` when the tab returns to its original slot.
+    #[must_use]
+    pub fn on_reorder_preview<F>(mut self, on_reorder_preview: F) -> Self
+    where
+        F: 'static + Fn(usize, usize) -> Message,
+    {
+        self.on_reorder_preview = Some(Arc::new(on_reorder_preview));
+        self
+    }
+
+    /// Sets the message that will be produced when the user renames a tab.
+    ///
+    /// Setting this lets the user double-click a tab with a text label to edit
+    /// the label in place. Enter or clicking elsewhere commits the new name,
+    /// and Escape cancels. The function receives the id of the tab and the new
+    /// name; the application is responsible for updating its [`TabLabel`].
+    /// Selection and dragging are suspended while editing.
+    #[must_use]
+    pub fn on_rename<F>(mut self, on_rename: F) -> Self
+    where
+        F: 'static + Fn(TabId, String) -> Message,
+    {
+        self.on_rename = Some(Arc::new(on_rename));
+        self
+    }
+
+    /// Sets the message that will be produced when the user asks to close
+    /// every tab except one. It receives the id of the tab to keep.
+    ///
+    /// The gesture is Alt+middle-click on a tab. Removing the other tabs is up
+    /// to the application; a context menu of the application can produce the
+    /// same message for a "Close Others" entry.
+    #[must_use]
+    pub fn on_close_others<F>(mut self, on_close_others: F) -> Self
+    where
+        F: 'static + Fn(TabId) -> Message,
+    {
+        self.on_close_others = Some(Arc::new(on_close_others));
+        self
+    }
+
+    /// Sets the message that will be produced when a tab is clicked on its edge
+    /// rather than its body.
+    ///
+    /// A click counts as an edge click within the tab's border width plus 3
+    /// pixels of its left or right edge. Edge clicks don't select the tab or
+    /// start a drag, which makes them a building block for resize handles.
+    #[must_use]
+    pub fn on_edge_click<F>(mut self, on_edge_click: F) -> Self
+    where
+        F: 'static + Fn(TabId) -> Message,
+    {
+        self.on_edge_click = Some(Arc::new(on_edge_click));
+        self
+    }
+
+    /// Sets the message that will be produced when the last remaining tab is
+    /// closed with its close icon or the keyboard.
+    ///
+    /// It is published right after the [`on_close`](Self::on_close) message, so
+    /// the application can show a placeholder or quit. Has no effect with
+    /// [`on_close_request`](Self::on_close_request), since the close may still
+    /// be declined.
+    #[must_use]
+    pub fn on_empty<F>(mut self, on_empty: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_empty = Some(Arc::new(on_empty));
+        self
+    }
+
+    /// Sets the message that will be produced when the close-all button is
+    /// clicked.
+    ///
+    /// The button is only shown when this is set. It sits at the end of the
+    /// bar, after the trailing tabs, and is styled like the close icon of the
+    /// tabs. Closing the tabs is left to the application.
+    #[must_use]
+    pub fn on_close_all<F>(mut self, on_close_all: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_close_all = Some(Arc::new(on_close_all));
+        self
+    }
+
+    /// Sets the minimum mouse movement (in pixels) before a press is
+    /// considered a drag. Defaults to `5.0`.
+    ///
+    /// Sets both thresholds of [`drag_thresholds`](Self::drag_thresholds).
+    /// Only meaningful when [`on_reorder`](Self::on_reorder) is set.
+    #[must_use]
+    pub fn drag_threshold(self, threshold: f32) -> Self {
+        self.drag_thresholds(threshold, threshold)
+    }
+
+    /// Sets separate drag thresholds for both axes, in pixels. Both default
+    /// to `5.0`.
+    ///
+    /// A press turns into a drag once it moves `reorder` horizontally or
+    /// `detach` vertically. With a [`drag_channel`](Self::drag_channel), the
+    /// dragged tab is only offered to other bars once it is pulled more than
+    /// `detach` above or below the strip, so a larger value makes reorders
+    /// start easily while detaching takes a deliberate pull.
+    #[must_use]
+    pub fn drag_thresholds(mut self, reorder: f32, detach: f32) -> Self {
+        self.reorder_threshold = reorder;
+        self.detach_threshold = detach;
+        self
+    }
+
+    /// Sets how long a finger must stay down on a tab before moving it starts a
+    /// reorder. Defaults to 350 ms.
+    ///
+    /// Touch movement before the delay is treated as a scroll. Mouse drags only
+    /// use the [`drag_threshold`](Self::drag_threshold).
+    #[must_use]
+    pub fn touch_reorder_delay(mut self, delay: Duration) -> Self {
+        self.touch_reorder_delay = delay;
+        self
+    }
+
+    /// Sets whether a tab is selected when the press on it is released rather
+    /// than when it is pressed. Defaults to `false`.
+    ///
+    /// With this set, dragging a tab to reorder it never selects it: the
+    /// selection only happens if the press stays under the
+    /// [`drag_threshold`](Self::drag_threshold) and is released over the tab.
+    #[must_use]
+    pub fn select_on_release(mut self, select_on_release: bool) -> Self {
+        self.select_on_release = select_on_release;
+        self
+    }
+
+    /// Sets what clicking, or pressing Enter on, the tab that is already
+    /// active does. Defaults to [`ReselectBehavior::Emit`], which publishes
+    /// `on_select` again.
+    #[must_use]
+    pub fn reselect_behavior(mut self, behavior: ReselectBehavior<Message, TabId>) -> Self {
+        self.reselect_behavior = behavior;
+        self
+    }
+
+    /// Sets the cursor shown while a tab is being dragged. Defaults to
+    /// [`mouse::Interaction::Grabbing`].
+    ///
+    /// Only meaningful when [`on_reorder`](Self::on_reorder) is set.
+    #[must_use]
+    pub fn drag_cursor(mut self, cursor: mouse::Interaction) -> Self {
+        self.drag_cursor = cursor;
+        self
+    }
+
+    /// Sets the opacity and scale of the tab that follows the cursor while
+    /// dragging. Defaults to full opacity at the original size.
+    ///
+    /// Only meaningful when [`on_reorder`](Self::on_reorder) is set.
+    #[must_use]
+    pub fn drag_overlay_style(mut self, style: DragOverlayStyle) -> Self {
+        self.drag_overlay_style = style;
+        self
+    }
+
+    /// Sets how far, in pixels, the dragged tab may follow the cursor above
+    /// or below its row. Defaults to no limit.
+    ///
+    /// A small band, e.g. `10.0`, lets the tab lift off the bar while it
+    /// slides. Once the tab is dragged out towards another bar sharing the
+    /// [`drag_channel`](Self::drag_channel), it follows the cursor freely.
+    #[must_use]
+    pub fn drag_lift(mut self, lift: f32) -> Self {
+        self.drag_lift = lift.max(0.0);
+        self
+    }
+
+    /// Sets whether a dragged tab follows the cursor in a floating overlay.
+    /// Defaults to `true`.
+    ///
+    /// When disabled, the dragged tab is drawn in the slot it would drop
+    /// into, with the [`Status::Dragging`] style, while its neighbours shift
+    /// as usual. This avoids redrawing an overlay on every cursor move, which
+    /// can stutter on slow or remote backends.
+    #[must_use]
+    pub fn drag_overlay(mut self, drag_overlay: bool) -> Self {
+        self.drag_overlay = drag_overlay;
+        self
+    }
+
+    /// Sets how long a tab takes to fade out and collapse after its close
+    /// button is clicked. Defaults to zero, which removes it immediately.
+    ///
+    /// The animation starts once the app removes the tab; if it doesn't (e.g.
+    /// an [`on_close_request`](Self::on_close_request) is declined), nothing is
+    /// animated.
+    #[must_use]
+    pub fn close_animation(mut self, duration: Duration) -> Self {
+        self.close_animation = duration;
+        self
+    }
+
+    /// Sets how long the background of a tab takes to fade between its
+    /// inactive and hovered styles. Defaults to zero, which switches
+    /// instantly; around 80 ms gives a softer feel.
+    ///
+    /// Only solid color backgrounds are blended.
+    #[must_use]
+    pub fn hover_animation(mut self, duration: Duration) -> Self {
+        self.hover_animation = duration;
+        self
+    }
+
+    /// Sets how long the active tab indicator takes to slide from the
+    /// previously active tab to a newly selected one. Defaults to zero, which
+    /// moves it instantly.
+    ///
+    /// The indicator is only drawn when the active style sets a
+    /// [`TabStyle::indicator`](crate::TabStyle::indicator) width.
+    #[must_use]
+    pub fn indicator_animation(mut self, duration: Duration) -> Self {
+        self.indicator_animation = duration;
+        self
+    }
+
+    /// Sets the padding, spacing, text, icon and close sizes, and height of
+    /// the [`TabBar`] at once. See [`Density`] for the values of each preset.
+    ///
+    /// The preset overwrites these settings, so call it first: setters called
+    /// after it override its values, while those called before it are lost.
+    #[must_use]
+    pub fn density(mut self, density: Density) -> Self {
+        let (padding, spacing, text_size, icon_size, close_size, height) = match density {
+            Density::Compact => (
+                Padding::from([2.0, 6.0]),
+                0.0,
+                13.0,
+                13.0,
+                12.0,
+                Length::Fixed(24.0),
+            ),
+            Density::Comfortable => (
+                DEFAULT_PADDING,
+                DEFAULT_SPACING.0,
+                DEFAULT_TEXT_SIZE,
+                DEFAULT_ICON_SIZE,
+                DEFAULT_CLOSE_SIZE,
+                Length::Shrink,
+            ),
+            Density::Spacious => (
+                Padding::from([8.0, 12.0]),
+                4.0,
+                18.0,
+                18.0,
+                18.0,
+                Length::Fixed(44.0),
+            ),
+        };
+        self.padding = padding;
+        self.spacing = Pixels(spacing);
+        self.text_size = text_size;
+        self.icon_size = icon_size;
+        self.close_size = close_size;
+        self.height = height;
+        self
+    }
+
+    /// Sets whether the bar itself is drawn with the [`Status::Hovered`]
+    /// style while the cursor is anywhere over it. Defaults to `false`: the
+    /// bar always uses the [`Status::Inactive`] style, and only the hovered
+    /// tab changes.
+    #[must_use]
+    pub fn bar_hover(mut self, bar_hover: bool) -> Self {
+        self.bar_hover = bar_hover;
+        self
+    }
+
+    /// Sets the padding of the tabs of the [`TabBar`].
+    #[must_use]
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the padding between the edge of the [`TabBar`] and its tabs.
+    /// Defaults to `0`.
+    ///
+    /// The bar's background and border still cover the padding.
+    #[must_use]
+    pub fn bar_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.bar_padding = padding.into();
+        self
+    }
+
+    /// Pushes a [`TabLabel`] to the [`TabBar`].
+    #[must_use]
+    pub fn push(mut self, id: TabId, tab_label: TabLabel) -> Self {
+        self.insert_tab(self.scrolling_count(), id, tab_label, None, None);
+        self
+    }
+
+    /// Pushes a [`TabLabel`] to the trailing group of the [`TabBar`].
+    ///
+    /// Trailing tabs stay pinned to the right edge of the bar, outside the
+    /// scrolling area. They can be selected and closed and show tooltips like
+    /// any other tab, but are not part of drag-and-drop reordering.
+    #[must_use]
+    pub fn push_trailing(mut self, id: TabId, tab_label: TabLabel) -> Self {
+        self.insert_tab(self.tab_indices.len(), id, tab_label, None, None);
+        self.trailing_count += 1;
+        self
+    }
+
+    /// Inserts a tab at `index` into all per-tab vectors.
+    fn insert_tab(
+        &mut self,
+        index: usize,
+        id: TabId,
+        tab_label: TabLabel,
+        tooltip: Option<TooltipContent<'a, Message, Theme, Renderer>>,
+        name: Option<String>,
+    ) {
+        self.tab_labels.insert(index, tab_label);
+        self.tab_indices.insert(index, id);
+        self.tab_statuses.insert(index, (None, None));
+        self.tab_tooltips.insert(index, tooltip);
+        self.tab_names.insert(index, name);
+        self.tab_backgrounds.insert(index, None);
+        self.tab_close_colors.insert(index, None);
+        self.tab_locked.insert(index, false);
+    }
+
+    /// Replaces all tabs of the [`TabBar`] with the `(TabId, TabLabel)` pairs
+    /// of the iterator.
+    ///
+    /// Tooltips, names, backgrounds, close colors, locks and sections of the
+    /// previous tabs are dropped, trailing tabs become regular ones and the
+    /// active tab index is clamped to the new tabs. Every other setting is
+    /// kept.
+    #[must_use]
+    pub fn tabs<I>(mut self, tabs: I) -> Self
+    where
+        I: IntoIterator<Item = (TabId, TabLabel)>,
+    {
+        self.tab_labels.clear();
+        self.tab_indices.clear();
+        self.tab_statuses.clear();
+        self.tab_tooltips.clear();
+        self.tab_names.clear();
+        self.tab_backgrounds.clear();
+        self.tab_close_colors.clear();
+        self.tab_locked.clear();
+        self.sections.clear();
+        self.trailing_count = 0;
+
+        self = self.extend(tabs);
+        self.active_tab = self
+            .active_tab
+            .map(|active_tab| active_tab.min(self.tab_indices.len().saturating_sub(1)));
+        self
+    }
+
+    /// Pushes every `(TabId, TabLabel)` pair of the iterator to the [`TabBar`].
+    ///
+    /// Equivalent to calling [`push`](Self::push) for each entry, but
+    /// reserves space for all of them up front.
+    #[must_use]
+    pub fn extend<I>(mut self, tabs: I) -> Self
+    where
+        I: IntoIterator<Item = (TabId, TabLabel)>,
+    {
+        let tabs = tabs.into_iter();
+        let (additional, _) = tabs.size_hint();
+        self.tab_labels.reserve(additional);
+        self.tab_indices.reserve(additional);
+        self.tab_statuses.reserve(additional);
+        self.tab_tooltips.reserve(additional);
+        self.tab_names.reserve(additional);
+        self.tab_backgrounds.reserve(additional);
+        self.tab_close_colors.reserve(additional);
+        self.tab_locked.reserve(additional);
+
+        let scrolling_count = self.scrolling_count();
+        for (id, tab_label) in tabs {
+            self.tab_labels.push(tab_label);
+            self.tab_indices.push(id);
+            self.tab_statuses.push((None, None));
+            self.tab_tooltips.push(None);
+            self.tab_names.push(None);
+            self.tab_backgrounds.push(None);
+            self.tab_close_colors.push(None);
+            self.tab_locked.push(false);
+        }
+
+        // Move the trailing tabs back behind the new ones.
+        if self.trailing_count > 0 {
+            let trailing_count = self.trailing_count;
+            self.tab_labels[scrolling_count..].rotate_left(trailing_count);
+            self.tab_indices[scrolling_count..].rotate_left(trailing_count);
+            self.tab_statuses[scrolling_count..].rotate_left(trailing_count);
+            self.tab_tooltips[scrolling_count..].rotate_left(trailing_count);
+            self.tab_names[scrolling_count..].rotate_left(trailing_count);
+            self.tab_backgrounds[scrolling_count..].rotate_left(trailing_count);
+            self.tab_close_colors[scrolling_count..].rotate_left(trailing_count);
+            self.tab_locked[scrolling_count..].rotate_left(trailing_count);
+        }
+        self
+    }
+
+    /// Pushes a [`TabLabel`] with an associated tooltip to the [`TabBar`].
+    ///
+    /// The tooltip text will appear after the configured
+    /// [`tooltip_delay`](Self::tooltip_delay) when the cursor hovers over the tab.
+    #[must_use]
+    pub fn push_with_tooltip(
+        mut self,
+        id: TabId,
+        tab_label: TabLabel,
+        tooltip: impl Into<String>,
+    ) -> Self {
+        self.insert_tab(
+            self.scrolling_count(),
+            id,
+            tab_label,
+            Some(TooltipContent::Text(tooltip.into())),
+            None,
+        );
+        self
+    }
+
+    /// Pushes a [`TabLabel`] with a tooltip built from a custom element to the
+    /// [`TabBar`].
+    ///
+    /// `view` is called whenever the tooltip is shown, and the element is laid
+    /// out and drawn on the tooltip background, within its padding and
+    /// maximum width. Use [`push_with_tooltip`](Self::push_with_tooltip) for
+    /// plain text.
+    #[must_use]
+    pub fn push_with_tooltip_element(
+        mut self,
+        id: TabId,
+        tab_label: TabLabel,
+        view: impl Fn() -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        self.insert_tab(
+            self.scrolling_count(),
+            id,
+            tab_label,
+            Some(TooltipContent::Element(Arc::new(view))),
+            None,
+        );
+        self
+    }
+
+    /// Pushes a [`TabLabel`] with a human-readable name to the [`TabBar`].
+    ///
+    /// The name is shown as the tab's tooltip when
+    /// [`auto_tooltip`](Self::auto_tooltip) is enabled, which is useful for
+    /// [`TabLabel::Icon`] tabs that have no visible title.
+    #[must_use]
+    pub fn push_with_name(
+        mut self,
+        id: TabId,
+        tab_label: TabLabel,
+        name: impl Into<String>,
+    ) -> Self {
+        self.insert_tab(
+            self.scrolling_count(),
+            id,
+            tab_label,
+            None,
+            Some(name.into()),
+        );
+        self
+    }
+
+    /// Locks the tabs with the given ids in place.
+    ///
+    /// Locked tabs can still be selected and closed, but they can't be
+    /// dragged, and other tabs can't be moved past them. Call this after the
+    /// tabs have been pushed; tabs pushed later are not locked.
+    #[must_use]
+    pub fn locked(mut self, ids: &[TabId]) -> Self {
+        for (locked, id) in self.tab_locked.iter_mut().zip(&self.tab_indices) {
+            *locked = ids.contains(id);
+        }
+        self
+    }
+
+    /// Pushes a section header to the [`TabBar`].
+    ///
+    /// The header is shown before the next tab that is pushed. It cannot be
+    /// selected, closed or dragged, and tabs are only reordered within the
+    /// section they belong to.
+    #[must_use]
+    pub fn push_section(mut self, label: impl Into<String>) -> Self {
+        self.sections.push((self.scrolling_count(), label.into()));
+        self
+    }
+
+    /// Removes the tab with the given id from the [`TabBar`], if present.
+    ///
+    /// The active tab keeps pointing at the same tab when a tab before it is
+    /// removed. Removing the active tab itself activates the tab that took its
+    /// place, or the new last tab if it was the last one.
+    #[must_use]
+    pub fn remove(mut self, id: &TabId) -> Self {
+        if let Some(index) = self.tab_indices.iter().position(|tab_id| tab_id == id) {
+            let active_tab = self.active_index();
+            if self.active_id.as_ref() == Some(id) {
+                self.active_id = None;
+            }
+            if index >= self.scrolling_count() {
+                self.trailing_count -= 1;
+            }
+            self.tab_labels.remove(index);
+            self.tab_indices.remove(index);
+            self.tab_statuses.remove(index);
+            self.tab_tooltips.remove(index);
+            self.tab_names.remove(index);
+            self.tab_backgrounds.remove(index);
+            self.tab_close_colors.remove(index);
+            self.tab_locked.remove(index);
+            for (position, _) in &mut self.sections {
+                if *position > index {
+                    *position -= 1;
+                }
+            }
+
+            self.active_tab = active_tab.map(|active_tab| {
+                if index < active_tab {
+                    active_tab - 1
+                } else {
+                    active_tab.min(self.tab_indices.len().saturating_sub(1))
+                }
+            });
+        }
+        self
+    }
+
+    /// Gets the amount of tabs on the [`TabBar`].
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.tab_indices.len()
+    }
+
+    /// Sets the spacing between the tabs of the [`TabBar`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+
+    /// Sets the spacing between a tab's label content and its close button.
+    #[must_use]
+    pub fn close_spacing(mut self, close_spacing: f32) -> Self {
+        self.close_spacing = close_spacing;
+        self
+    }
+
+    /// Sets the spacing between the icon and text in [`TabLabel::IconText`] mode.
+    ///
+    /// Applies to every [`Position`]; use
+    /// [`icon_text_spacing`](Self::icon_text_spacing) to set side-by-side and
+    /// stacked labels apart. Has no effect when using [`TabLabel::Icon`] or
+    /// [`TabLabel::Text`].
+    #[must_use]
+    pub fn icon_spacing(self, icon_spacing: f32) -> Self {
+        self.icon_text_spacing(icon_spacing, icon_spacing)
+    }
+
+    /// Sets the spacing between the icon and text in [`TabLabel::IconText`]
+    /// mode separately for side-by-side labels ([`Position::Left`] and
+    /// [`Position::Right`]) and stacked ones ([`Position::Top`] and
+    /// [`Position::Bottom`]).
+    #[must_use]
+    pub fn icon_text_spacing(mut self, horizontal: f32, vertical: f32) -> Self {
+        self.icon_spacing = horizontal;
+        self.vertical_icon_spacing = vertical;
+        self
+    }
+
+    /// The icon spacing for the current [`Position`].
+    fn position_icon_spacing(&self) -> f32 {
+        if self.position.is_vertical() {
+            self.vertical_icon_spacing
+        } else {
+            self.icon_spacing
+        }
+    }
+
+    /// Sets a filter text to highlight matching tabs, e.g. for a quick
+    /// switcher.
+    ///
+    /// Tabs whose text label contains the filter, ignoring case, show the
+    /// matching part in the style's
+    /// [`filter_match_color`](crate::TabStyle::filter_match_color); all other
+    /// tabs are dimmed. `None` or an empty filter draws the tabs as usual.
+    #[must_use]
+    pub fn filter(mut self, filter: Option<String>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets whether Ctrl+W (Cmd+W on macOS) closes the tab with keyboard
+    /// focus. Defaults to `true`. See [`close_on_delete`](Self::close_on_delete)
+    /// for Delete and Backspace.
+    ///
+    /// Closing goes through [`on_close`](Self::on_close) or
+    /// [`on_close_request`](Self::on_close_request) like a click on the close
+    /// button, so it does nothing without either. The shortcuts are ignored
+    /// while a tab is renamed.
+    #[must_use]
+    pub fn keyboard_close(mut self, keyboard_close: bool) -> Self {
+        self.keyboard_close = keyboard_close;
+        self
+    }
+
+    /// Sets whether bare Delete and Backspace also close the tab with
+    /// keyboard focus. Defaults to `false`, since those keys are easily
+    /// pressed by accident; has no effect with `keyboard_close(false)`.
+    #[must_use]
+    pub fn close_on_delete(mut self, close_on_delete: bool) -> Self {
+        self.close_on_delete = close_on_delete;
+        self
+    }
+
+    /// Sets how tabs that don't fit the width of the bar are handled.
+    ///
+    /// With [`OverflowBehavior::CollapseToIcons`], collapsed tabs show their
+    /// text as a tooltip, as with [`auto_tooltip`](Self::auto_tooltip). With
+    /// [`OverflowBehavior::Wrap`], [`height`](Self::height) is the height of
+    /// each line of tabs.
+    ///
+    /// Default: [`OverflowBehavior::Scroll`].
+    #[must_use]
+    pub fn overflow_behavior(mut self, behavior: OverflowBehavior) -> Self {
+        self.overflow_behavior = behavior;
+        self
+    }
+
+    /// Sets the id of the scrollable area holding the tabs.
+    ///
+    /// The scroll position lives in the widget tree, so it survives view
+    /// rebuilds and tab changes (clamped to the new content width) as long as
+    /// the bar stays at the same place in the view. Give each bar its own id
+    /// to target it with scrollable operations such as
+    /// [`scroll_to`](iced::widget::operation::scroll_to), e.g. to restore a
+    /// saved offset after the bar has been moved.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the scroll behavior of the [`TabBar`].
+    ///
+    /// Use [`ScrollMode::Floating`] for a floating scrollbar,
+    /// [`ScrollMode::FloatingAutoHide`] for one that only shows while the bar
+    /// is hovered or scrolled,
+    /// [`ScrollMode::Below`] for an always-visible embedded scrollbar,
+    /// or [`ScrollMode::NoScrollbar`] to hide the scrollbar entirely (mouse wheel only).
+    #[must_use]
+    pub fn scroll_mode(mut self, mode: ScrollMode) -> Self {
+        self.scroll_mode = mode;
+        self
+    }
+
+    /// Connects the [`TabBar`] to a [`TabDragChannel`] shared with other bars,
+    /// so its tabs can be dragged into them and theirs into it.
+    ///
+    /// Dragging a tab out requires reordering to be enabled with
+    /// [`on_reorder`](Self::on_reorder) or [`on_reorder_ids`](Self::on_reorder_ids).
+    /// Trailing tabs can't be dragged between bars.
+    #[must_use]
+    pub fn drag_channel(mut self, channel: TabDragChannel<TabId>) -> Self {
+        self.drag_channel = Some(channel);
+        self
+    }
+
+    /// Sets where the tabs sit in the bar when they are narrower than it.
+    /// Defaults to [`Alignment::Start`].
+    ///
+    /// Once the tabs overflow the bar they scroll from the left edge as
+    /// usual. Trailing tabs stay pinned to the right edge either way.
+    #[must_use]
+    pub fn tab_alignment(mut self, alignment: Alignment) -> Self {
+        self.tab_alignment = alignment;
+        self
+    }
+
+    /// Sets whether the tabs are shown in reverse order, e.g. most recent
+    /// first. Defaults to `false`.
+    ///
+    /// Only the presentation changes: tabs are still pushed in the usual
+    /// order, and every index passed to the callbacks, as well as the ids of
+    /// [`on_reorder_ids`](Self::on_reorder_ids), are in that order. Trailing
+    /// tabs keep their order.
+    #[must_use]
+    pub fn reversed(mut self, reversed: bool) -> Self {
+        self.reversed = reversed;
+        self
+    }
+
+    /// Sets how far the mouse wheel and the keyboard scroll the tabs.
+    #[must_use]
+    pub fn scroll_config(mut self, config: ScrollConfig) -> Self {
+        self.scroll_config = config;
+        self
+    }
+
+    /// Sets the width of the scrollbar and its thumb, e.g. a slimmer rail
+    /// for [`ScrollMode::Below`].
+    #[must_use]
+    pub fn scrollbar_style(mut self, style: ScrollbarStyle) -> Self {
+        self.scrollbar_style = style;
+        self
+    }
+
+    /// Sets how far Page Up/Down scroll the tabs while a tab has keyboard
+    /// focus. Defaults to `None`, which scrolls by the visible width of the
+    /// tab strip.
+    ///
+    /// Shorthand for the [`keyboard_step`](ScrollConfig::keyboard_step) of
+    /// [`scroll_config`](Self::scroll_config).
+    #[must_use]
+    pub fn scroll_page_size(mut self, page_size: Option<f32>) -> Self {
+        self.scroll_config.keyboard_step = page_size;
+        self
+    }
+
+    /// Sets whether the tabs settle on a tab edge after scrolling. Defaults to
+    /// `false`.
+    ///
+    /// Shortly after the last wheel or Page Up/Down scroll, the strip scrolls
+    /// the nearest tab edge to the start of the visible area, so a tab is no
+    /// longer cut in half there.
+    #[must_use]
+    pub fn scroll_snap(mut self, snap: bool) -> Self {
+        self.scroll_snap = snap;
+        self
+    }
+
+    /// Sets the tooltip shown when hovering the close button of a tab.
+    ///
+    /// While the close button is hovered, this replaces the tab's own tooltip.
+    /// Only meaningful when [`on_close`](Self::on_close) or
+    /// [`on_close_request`](Self::on_close_request) is set.
+    #[must_use]
+    pub fn close_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.close_tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Sets whether tabs without an explicit tooltip show one anyway.
+    ///
+    /// The tooltip is the name given with
+    /// [`push_with_name`](Self::push_with_name) or, failing that, the tab's
+    /// text label. Tooltips set with
+    /// [`push_with_tooltip`](Self::push_with_tooltip) take precedence.
+    ///
+    /// Default: `false`.
+    #[must_use]
+    pub fn auto_tooltip(mut self, auto_tooltip: bool) -> Self {
+        self.auto_tooltip = auto_tooltip;
+        self
+    }
+
+    /// Sets where tooltips are placed.
+    ///
+    /// Close button tooltips always follow the cursor.
+    ///
+    /// Default: [`TooltipAnchor::Cursor`].
+    #[must_use]
+    pub fn tooltip_anchor(mut self, anchor: TooltipAnchor) -> Self {
+        self.tooltip_anchor = anchor;
+        self
+    }
+
+    /// Sets the delay before a tooltip appears when hovering a tab.
+    ///
+    /// Default: 500 ms. Only affects tabs that have a tooltip, see
+    /// [`push_with_tooltip`](Self::push_with_tooltip) and
+    /// [`auto_tooltip`](Self::auto_tooltip).
+    ///
+    /// A zero delay shows the tooltip as soon as the tab is hovered.
+    /// Otherwise a redraw is scheduled for when the delay elapses, so the
+    /// tooltip appears even if the cursor doesn't move.
+    #[must_use]
+    pub fn tooltip_delay(mut self, delay: Duration) -> Self {
+        self.tooltip_delay = delay;
         self
     }
 
@@ -468,11 +2100,52 @@ where
 
     /// Sets up the active tab on the [`TabBar`].
     ///
-    /// If the given `TabId` is not found, the active tab index remains unchanged.
+    /// The id is looked up whenever the active tab is needed, so it can be set
+    /// before the tabs are pushed and keeps pointing at the same tab if the
+    /// order changes. If no tab has the given `TabId`, the active tab index
+    /// remains unchanged.
     #[must_use]
     pub fn set_active_tab(mut self, active_tab: &TabId) -> Self {
-        if let Some(idx) = self.tab_indices.iter().position(|id| id == active_tab) {
-            self.active_tab = idx;
+        self.active_tab = self.active_index();
+        self.active_id = Some(active_tab.clone());
+        self
+    }
+
+    /// Leaves the [`TabBar`] without an active tab, e.g. before the user has
+    /// picked one. Every tab is then drawn as inactive until
+    /// [`set_active_tab`](Self::set_active_tab) is called.
+    #[must_use]
+    pub fn clear_active(mut self) -> Self {
+        self.active_tab = None;
+        self.active_id = None;
+        self
+    }
+
+    /// Sets the background of the tab with the given id, replacing the
+    /// style's background in every status.
+    ///
+    /// Any [`Background`] works, so gradients can be used as well. The
+    /// background is clipped to the tab's border radius. Call this after the
+    /// tab has been pushed; unknown ids are ignored.
+    #[must_use]
+    pub fn set_tab_background(mut self, id: &TabId, background: impl Into<Background>) -> Self {
+        if let Some(index) = self.tab_indices.iter().position(|tab_id| tab_id == id) {
+            self.tab_backgrounds[index] = Some(background.into());
+        }
+        self
+    }
+
+    /// Sets the color of the close icon of the tab with the given id,
+    /// replacing the style's text color, e.g. to match or contrast with a
+    /// [`set_tab_background`](Self::set_tab_background).
+    ///
+    /// The style's [`close_hover_icon_color`](crate::TabStyle::close_hover_icon_color)
+    /// still applies while the close button is hovered. Call this after the
+    /// tab has been pushed; unknown ids are ignored.
+    #[must_use]
+    pub fn set_close_color(mut self, id: &TabId, color: Color) -> Self {
+        if let Some(index) = self.tab_indices.iter().position(|tab_id| tab_id == id) {
+            self.tab_close_colors[index] = Some(color);
         }
         self
     }
@@ -485,8 +2158,12 @@ where
     }
 
     /// Sets the style of the [`TabBar`].
+    ///
+    /// The function receives the [`StyleArgs`] of each tab, so it can vary
+    /// the style with the tab's position, e.g. to round only the outer
+    /// corners of the first and last tabs.
     #[must_use]
-    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    pub fn style(mut self, style: impl Fn(&Theme, StyleArgs) -> Style + 'a) -> Self
     where
         <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme, Style>>,
     {
@@ -508,61 +2185,369 @@ where
         self
     }
 
-    fn scrollbar_direction(&self) -> scrollable::Direction {
+    fn scrollbar_direction(&self, show_scrollbar: bool) -> scrollable::Direction {
+        let style = self.scrollbar_style;
+        let visible = scrollable::Scrollbar::new()
+            .width(style.width)
+            .scroller_width(style.scroller_width)
+            .margin(style.margin);
         let scrollbar = match self.scroll_mode {
-            ScrollMode::Floating => scrollable::Scrollbar::default(),
-            ScrollMode::Below(spacing) => scrollable::Scrollbar::default().spacing(spacing),
+            ScrollMode::Floating => visible,
+            ScrollMode::FloatingAutoHide if show_scrollbar => visible,
+            ScrollMode::FloatingAutoHide => scrollable::Scrollbar::hidden(),
+            ScrollMode::Below(spacing) => visible.spacing(spacing),
             ScrollMode::NoScrollbar => scrollable::Scrollbar::hidden(),
         };
         scrollable::Direction::Horizontal(scrollbar)
     }
 
-    fn tab_content(&self) -> tab::Tab<'_, 'a, Message, TabId, Theme, Renderer> {
+    /// Top-left corner of the dragged tab with the cursor at `cursor`, kept
+    /// within [`drag_lift`](Self::drag_lift) of its row unless the tab is
+    /// offered to other bars.
+    fn dragged_tab_position(&self, drag: &tab::DragState, cursor: Point) -> Point {
+        let y = cursor.y - drag.tab_offset_y;
+        let dragged_out = self.drag_channel.as_ref().is_some_and(|channel| {
+            channel
+                .borrow()
+                .as_ref()
+                .is_some_and(|external| !external.released)
+        });
+        let y = if dragged_out {
+            y
+        } else {
+            let row_y = drag.press_origin.y - drag.tab_offset_y;
+            y.clamp(row_y - self.drag_lift, row_y + self.drag_lift)
+        };
+        Point::new(cursor.x - drag.tab_offset_x, y)
+    }
+
+    /// The callback fired by the close icon: `on_close`, falling back to
+    /// `on_close_request`.
+    fn close_callback(&self) -> Option<&dyn Fn(usize, TabId) -> Message> {
+        self.on_close
+            .as_deref()
+            .or(self.on_close_request.as_deref())
+    }
+
+    /// The number of tabs in the scrolling group, i.e. not pushed with
+    /// [`push_trailing`](Self::push_trailing).
+    fn scrolling_count(&self) -> usize {
+        self.tab_indices.len() - self.trailing_count
+    }
+
+    /// Maps the index of a tab as it is shown in the bar to its index in the
+    /// application's order, and back. Only the scrolling tabs are reversed.
+    fn shown_index(&self, index: usize) -> usize {
+        let scrolling_count = self.scrolling_count();
+        if self.reversed && index < scrolling_count {
+            scrolling_count - 1 - index
+        } else {
+            index
+        }
+    }
+
+    /// Builds the row for the tabs in `range`: the scrolling group or the
+    /// trailing group.
+    fn tab_group(
+        &self,
+        range: Range<usize>,
+        scrollable: bool,
+    ) -> tab::Tab<'_, 'a, Message, TabId, Theme, Renderer> {
+        let active_tab = self.active_index();
+        // Sections, reordering and dragging between bars only apply to the
+        // scrolling group. Drop targets are found along a single line, so
+        // wrapped tabs can't be dragged.
+        let (sections, on_reorder, on_reorder_ids, on_move, drag_channel) =
+            if scrollable && self.overflow_behavior == OverflowBehavior::Wrap {
+                (self.sections.as_slice(), None, None, None, None)
+            } else if scrollable {
+                (
+                    self.sections.as_slice(),
+                    self.on_reorder.as_deref(),
+                    self.on_reorder_ids.as_deref(),
+                    self.on_move.as_deref(),
+                    self.drag_channel.as_ref(),
+                )
+            } else {
+                (&[][..], None, None, None, None)
+            };
+
         tab::Tab::new(
-            &self.tab_labels,
-            &self.tab_statuses,
-            &self.tab_indices,
-            &self.tab_tooltips,
+            &self.tab_labels[range.clone()],
+            &self.tab_statuses[range.clone()],
+            &self.tab_indices[range.clone()],
+            &self.tab_tooltips[range.clone()],
+            &self.tab_names[range.clone()],
+            &self.tab_backgrounds[range.clone()],
+            &self.tab_close_colors[range.clone()],
+            &self.tab_locked[range.clone()],
+            sections,
+            self.filter.as_deref().filter(|filter| !filter.is_empty()),
+            self.close_tooltip.is_some(),
+            self.auto_tooltip,
             self.icon_size,
             self.text_size,
             self.close_size,
+            self.close_hit_area,
+            self.layout_size_offset,
             self.close_spacing,
-            self.icon_spacing,
+            self.position_icon_spacing(),
             self.padding,
             self.spacing,
             self.font,
             self.text_font,
             self.height,
+            self.auto_height,
             self.position,
             self.tab_width,
-            self.drag_threshold,
-            self.on_close.is_some(),
-            self.active_tab
-                .min(self.tab_indices.len().saturating_sub(1)),
-            Arc::clone(&self.on_select),
-            self.on_close.as_ref().map(Arc::clone),
-            self.on_reorder.as_ref().map(Arc::clone),
+            self.reorder_threshold,
+            self.detach_threshold,
+            self.touch_reorder_delay,
+            self.select_on_release,
+            &self.reselect_behavior,
+            self.drag_cursor,
+            self.drag_overlay,
+            self.close_animation,
+            self.hover_animation,
+            self.indicator_animation,
+            self.overflow_behavior,
+            &self.close_icon,
+            self.close_callback().is_some(),
+            self.keyboard_close,
+            self.close_on_delete,
+            self.select_neighbor_on_close && self.on_close.is_some(),
+            active_tab
+                .filter(|active_tab| range.contains(active_tab))
+                .map(|active_tab| active_tab - range.start),
+            scrollable,
+            range.start,
+            scrollable && self.reversed,
+            self.tab_indices.len(),
+            self.on_select.as_ref(),
+            self.close_callback(),
+            on_reorder,
+            on_reorder_ids,
+            on_move,
+            self.on_tab_bounds.as_deref(),
+            self.on_overflow.as_deref(),
+            self.on_scroll_edge.as_deref(),
+            self.on_double_click.as_deref(),
+            self.on_new_tab.as_deref(),
+            self.on_drag_start.as_deref(),
+            self.on_drag_end.as_deref(),
+            self.on_drag_out.as_deref(),
+            self.on_accept_external.as_deref(),
+            self.on_reorder_preview.as_deref(),
+            self.on_rename.as_deref(),
+            self.on_close_others.as_deref(),
+            self.on_edge_click.as_deref(),
+            self.on_empty
+                .as_deref()
+                .filter(|_| self.on_close.is_some() && self.tab_indices.len() == 1),
+            drag_channel,
             self.tooltip_delay,
             &self.class,
         )
     }
 
-    /// Returns the inner element (Scrollable wrapping TabBarContent).
+    /// Returns the bounds of the close-all button, if it is shown.
+    fn close_all_bounds(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        self.on_close_all.as_ref()?;
+        let button = layout.children().last()?;
+        button.children().next().map(|space| space.bounds())
+    }
+
+    /// Returns the id of the inner [`Scrollable`].
+    fn scrollable_id(&self) -> Id {
+        self.id.clone().unwrap_or_else(|| Id::new(SCROLLABLE_ID))
+    }
+
+    /// Returns the inner element: the Scrollable wrapping the scrolling tabs,
+    /// followed by the trailing tabs and the close-all button.
     pub(crate) fn wrapper_element(&self) -> Element<'_, Message, Theme, Renderer> {
-        let content = self.tab_content();
+        self.wrapper_element_with(true)
+    }
+
+    /// Returns the inner element, with a [`ScrollMode::FloatingAutoHide`]
+    /// scrollbar shown or hidden. A floating scrollbar doesn't take part in
+    /// the layout, so only drawing needs to tell them apart.
+    fn wrapper_element_with(&self, show_scrollbar: bool) -> Element<'_, Message, Theme, Renderer> {
+        let scrolling_count = self.scrolling_count();
+        let content = self.tab_group(0..scrolling_count, true);
+
         let scrollable_height = match self.scroll_mode {
             ScrollMode::Below(_) => Length::Shrink,
-            _ => self.height,
+            _ => self.bar_height(),
+        };
+        let scrollable_width = if self.width == Length::Shrink {
+            Length::Shrink
+        } else {
+            Length::Fill
         };
-        let scrollable =
-            Scrollable::with_direction(Element::new(content), self.scrollbar_direction())
-                .width(self.width)
-                .height(scrollable_height);
+        let scrollable = Scrollable::with_direction(
+            Element::new(content),
+            self.scrollbar_direction(show_scrollbar),
+        )
+        .id(self.scrollable_id())
+        .width(scrollable_width)
+        .height(scrollable_height);
+
+        let mut row = Row::new()
+            .push(scrollable)
+            .spacing(self.spacing)
+            .padding(self.bar_padding)
+            .width(self.width);
+        if self.trailing_count > 0 {
+            let trailing = self.tab_group(scrolling_count..self.tab_indices.len(), false);
+            row = row.push(Element::new(trailing));
+        }
+        if self.on_close_all.is_some() {
+            // Sized like the close button of a tab and centered on the bar;
+            // drawn and clicked by the bar itself.
+            let size = self.close_size * self.close_hit_area.max(1.0) + self.layout_size_offset;
+            row = row.push(
+                Container::new(Space::new().width(size).height(size))
+                    .height(Length::Fill)
+                    .align_y(Vertical::Center),
+            );
+        }
+
+        Element::new(row)
+    }
+}
+
+/// Returns an [`Operation`] that finds the tab under `point`, in window
+/// coordinates, of the [`TabBar`] with the given [`id`](TabBar::id).
+///
+/// The outcome is the id of the tab, or `None` for points over the gaps
+/// between tabs, the scrollbar, tabs scrolled out of view, or outside the
+/// bar. Run it with [`iced::advanced::widget::operate`].
+pub fn tab_at<TabId>(id: impl Into<Id>, point: Point) -> impl Operation<Option<TabId>>
+where
+    TabId: 'static + Clone + Send,
+{
+    struct TabAt<TabId> {
+        id: Id,
+        point: Point,
+        tab: Option<TabId>,
+    }
+
+    impl<TabId: 'static + Clone + Send> Operation<Option<TabId>> for TabAt<TabId> {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Option<TabId>>)) {
+            operate(self);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if id == Some(&self.id)
+                && let Some(hits) = state.downcast_ref::<TabHits<TabId>>()
+            {
+                self.tab = hits
+                    .0
+                    .iter()
+                    .find(|(_, bounds)| bounds.is_some_and(|bounds| bounds.contains(self.point)))
+                    .map(|(tab, _)| tab.clone());
+            }
+        }
+
+        fn finish(&self) -> Outcome<Option<TabId>> {
+            Outcome::Some(self.tab.clone())
+        }
+    }
+
+    TabAt {
+        id: id.into(),
+        point,
+        tab: None,
+    }
+}
+
+/// Every tab of a [`TabBar`] with its window-space bounds, or `None` for tabs
+/// scrolled out of view. Handed to [`tab_at`] through [`Operation::custom`].
+struct TabHits<TabId>(Vec<(TabId, Option<Rectangle>)>);
 
-        Element::new(scrollable)
+/// Records the translation of the [`Scrollable`] with the given id.
+struct ScrollTranslation {
+    id: Id,
+    translation: Vector,
+}
+
+impl Operation for ScrollTranslation {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+        _state: &mut dyn operation::Scrollable,
+    ) {
+        if id == Some(&self.id) {
+            self.translation = translation;
+        }
     }
 }
 
+/// Returns the content states of the scrolling and the trailing tab group.
+///
+/// The tree is laid out as `TabBar -> Row -> [Scrollable -> Tab, Tab]`,
+/// possibly followed by the close-all button.
+fn content_states(
+    state: &mut Tree,
+) -> (
+    Option<&mut tab::TabBarContentState>,
+    Option<&mut tab::TabBarContentState>,
+) {
+    let Some(row) = state.children.first_mut() else {
+        return (None, None);
+    };
+    let split = row.children.len().min(1);
+    let (scrolling, trailing) = row.children.split_at_mut(split);
+    let scrolling = scrolling
+        .first_mut()
+        .and_then(|scrollable| scrollable.children.first_mut())
+        .map(|tree| tree.state.downcast_mut::<tab::TabBarContentState>());
+    let trailing = trailing
+        .first_mut()
+        .filter(|tree| tree.tag == tree::Tag::of::<tab::TabBarContentState>())
+        .map(|tree| tree.state.downcast_mut::<tab::TabBarContentState>());
+    (scrolling, trailing)
+}
+
+/// Returns the content state of the scrolling tab group, for reading.
+fn scrolling_state(state: &Tree) -> Option<&tab::TabBarContentState> {
+    state
+        .children
+        .first()?
+        .children
+        .first()?
+        .children
+        .first()
+        .map(|tree| tree.state.downcast_ref::<tab::TabBarContentState>())
+}
+
+/// Returns the visible bounds of the active tab from the last draw, in
+/// either tab group.
+fn active_bounds(state: &Tree) -> Option<Rectangle> {
+    let row = state.children.first()?;
+    let scrolling = row
+        .children
+        .first()
+        .and_then(|scrollable| scrollable.children.first());
+    let trailing = row
+        .children
+        .get(1)
+        .filter(|tree| tree.tag == tree::Tag::of::<tab::TabBarContentState>());
+    scrolling.into_iter().chain(trailing).find_map(|tree| {
+        tree.state
+            .downcast_ref::<tab::TabBarContentState>()
+            .active_bounds
+            .get()
+    })
+}
+
 /// Ensures that `children` has a first entry synchronised with `element`.
 ///
 /// If the child already exists it is diffed; otherwise a fresh tree is created
@@ -588,16 +2573,15 @@ where
 impl<Message, TabId, Theme, Renderer> Widget<Message, Theme, Renderer>
     for TabBar<'_, Message, TabId, Theme, Renderer>
 where
-    Renderer: renderer::Renderer
-        + iced::advanced::text::Renderer<Font = Font>
-        + iced::advanced::svg::Renderer,
+    Renderer:
+        renderer::Renderer + iced::advanced::text::Renderer<Font = Font> + tab::CloseIconRenderer,
     Theme: Catalog + text::Catalog + scrollable::Catalog + container::Catalog,
-    TabId: Eq + Clone,
+    TabId: 'static + Eq + Clone,
 {
     fn size(&self) -> Size<Length> {
         let height = match self.scroll_mode {
             ScrollMode::Below(_) => Length::Shrink,
-            _ => self.height,
+            _ => self.bar_height(),
         };
         Size::new(self.width, height)
     }
@@ -606,7 +2590,51 @@ where
         let mut element = self.wrapper_element();
         let tab_tree = ensure_child_tree(&mut tree.children, &mut element);
 
-        let limits = limits.max_height(self.max_height);
+        let limits = limits.max_height(self.max_height).max_width(self.max_width);
+        let mut node = element.as_widget_mut().layout(tab_tree, renderer, &limits);
+
+        // Let the tab row know the width to collapse its labels into. The
+        // width is only known once the bar has been laid out.
+        let fit_width = match self.overflow_behavior {
+            OverflowBehavior::Scroll => None,
+            OverflowBehavior::CollapseToIcons { .. } | OverflowBehavior::Wrap => node
+                .children()
+                .first()
+                .map(|scrollable| scrollable.size().width),
+        };
+        if let Some(content_state) = content_states(tree).0
+            && content_state.fit_width != fit_width
+        {
+            content_state.fit_width = fit_width;
+            let tab_tree = ensure_child_tree(&mut tree.children, &mut element);
+            node = element.as_widget_mut().layout(tab_tree, renderer, &limits);
+        }
+
+        // Shift the tabs within the scrollable when they don't fill it. The
+        // offset is part of the tab row's layout, so hit-testing and drop
+        // targets follow it; it needs a second pass whenever it changes.
+        let factor = match self.tab_alignment {
+            Alignment::Start => 0.0,
+            Alignment::Center => 0.5,
+            Alignment::End => 1.0,
+        };
+        let Some(scrollable) = node.children().first() else {
+            return node;
+        };
+        let Some(content) = scrollable.children().first() else {
+            return node;
+        };
+        let Some(content_state) = content_states(tree).0 else {
+            return node;
+        };
+        let natural_width = content.size().width - content_state.align_offset;
+        let align_offset = ((scrollable.size().width - natural_width).max(0.0) * factor).floor();
+        if (align_offset - content_state.align_offset).abs() < f32::EPSILON {
+            return node;
+        }
+        content_state.align_offset = align_offset;
+
+        let tab_tree = ensure_child_tree(&mut tree.children, &mut element);
         element.as_widget_mut().layout(tab_tree, renderer, &limits)
     }
 
@@ -622,10 +2650,10 @@ where
     ) {
         let bounds = layout.bounds();
         let is_mouse_over = cursor.position().is_some_and(|pos| bounds.contains(pos));
-        let style_sheet = if is_mouse_over {
-            Catalog::style(theme, &self.class, Status::Hovered)
+        let style_sheet = if self.bar_hover && is_mouse_over {
+            Catalog::style(theme, &self.class, StyleArgs::new(Status::Hovered))
         } else {
-            Catalog::style(theme, &self.class, Status::Inactive)
+            Catalog::style(theme, &self.class, StyleArgs::new(Status::Inactive))
         };
 
         if bounds.intersects(viewport) {
@@ -647,7 +2675,27 @@ where
             );
         }
 
-        let element = self.wrapper_element();
+        if let Some((color, width)) = style_sheet.bar.baseline
+            && bounds.intersects(viewport)
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - width,
+                        height: width,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+
+        let show_scrollbar = is_mouse_over
+            || scrolling_state(state)
+                .and_then(|content_state| content_state.scrolled_at)
+                .is_some_and(|scrolled| scrolled.elapsed() < SCROLLBAR_AUTO_HIDE_DELAY);
+        let element = self.wrapper_element_with(show_scrollbar);
         element.as_widget().draw(
             &state.children[0],
             renderer,
@@ -657,6 +2705,93 @@ where
             cursor,
             viewport,
         );
+
+        // The active tab breaks through the baseline when it reaches it. The
+        // tabs are drawn in their own layer, so the patch needs one above it.
+        if let Some((_, width)) = style_sheet.bar.baseline
+            && let Some(active) = active_bounds(state)
+            && active.y + active.height >= bounds.y + bounds.height - width - 0.5
+            && let Some(active_index) = self.active_index()
+        {
+            let tab_style = Catalog::style(
+                theme,
+                &self.class,
+                StyleArgs::new(Status::Active)
+                    .at(self.shown_index(active_index), self.tab_indices.len()),
+            )
+            .tab;
+            let background = self
+                .tab_backgrounds
+                .get(active_index)
+                .copied()
+                .flatten()
+                .unwrap_or(tab_style.background);
+            let inset = tab_style.border_width;
+            // The active tab indicator is drawn along the bottom of the tab,
+            // so the patch stops above it to leave it showing.
+            let top = bounds.y + bounds.height - width;
+            let bottom = (bounds.y + bounds.height)
+                .min(active.y + active.height - tab_style.indicator.width.max(0.0));
+            if bottom > top {
+                renderer.with_layer(bounds, |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: active.x + inset,
+                                y: top,
+                                width: (active.width - 2.0 * inset).max(0.0),
+                                height: bottom - top,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        background,
+                    );
+                });
+            }
+        }
+
+        if let Some(button) = self.close_all_bounds(layout)
+            && button.intersects(viewport)
+        {
+            let tab_style = Catalog::style(theme, &self.class, StyleArgs::new(Status::Hovered)).tab;
+            let is_hovered = cursor.is_over(button);
+            if is_hovered && let Some(background) = tab_style.close_hover_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: button,
+                        border: Border {
+                            radius: tab_style.close_border_radius,
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+            let color = if is_hovered {
+                tab_style
+                    .close_hover_icon_color
+                    .unwrap_or(tab_style.text_color)
+            } else {
+                tab_style.text_color
+            };
+            renderer.fill_text(
+                iced::advanced::text::Text {
+                    content: CODICON_CLOSE_ALL.to_string(),
+                    bounds: button.size(),
+                    size: Pixels(self.close_size),
+                    font: CODICON_FONT,
+                    align_x: text::Alignment::Center,
+                    align_y: Vertical::Center,
+                    line_height: text::LineHeight::Relative(1.0),
+                    shaping: text::Shaping::Auto,
+                    wrapping: text::Wrapping::default(),
+                },
+                button.center(),
+                color,
+                button,
+            );
+        }
     }
 
     fn tag(&self) -> tree::Tag {
@@ -684,6 +2819,52 @@ where
         let mut element = self.wrapper_element();
         let tab_tree = ensure_child_tree(&mut tree.children, &mut element);
 
+        // Tabs inside the scrollable are laid out unscrolled, so find out how
+        // far it is scrolled before reporting where the tabs are.
+        let mut scroll = ScrollTranslation {
+            id: self.scrollable_id(),
+            translation: Vector::ZERO,
+        };
+        element
+            .as_widget_mut()
+            .operate(tab_tree, layout, renderer, &mut scroll);
+
+        let scrolling_count = self.scrolling_count();
+        let mut hits = Vec::with_capacity(self.tab_indices.len());
+        let mut groups = layout.children();
+        if let Some(scrollable) = groups.next()
+            && let Some(content) = scrollable.children().next()
+        {
+            let visible = scrollable.bounds();
+            hits.extend(
+                self.tab_group(0..scrolling_count, true)
+                    .tab_layouts(content)
+                    .into_iter()
+                    .map(|tab| (tab.bounds() - scroll.translation).intersection(&visible)),
+            );
+        }
+        if self.trailing_count > 0
+            && let Some(trailing) = groups.next()
+        {
+            hits.extend(
+                self.tab_group(scrolling_count..self.tab_indices.len(), false)
+                    .tab_layouts(trailing)
+                    .into_iter()
+                    .map(|tab| Some(tab.bounds())),
+            );
+        }
+        // The layouts are in the order the tabs are shown in.
+        let hits = hits
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, hit)| Some((self.tab_indices.get(self.shown_index(i))?.clone(), hit)))
+            .collect();
+        operation.custom(
+            Some(&self.scrollable_id()),
+            layout.bounds(),
+            &mut TabHits(hits),
+        );
+
         element
             .as_widget_mut()
             .operate(tab_tree, layout, renderer, operation);
@@ -700,13 +2881,30 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
+        if let Some(on_close_all) = self.on_close_all.as_ref()
+            && let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            && let Some(button) = self.close_all_bounds(layout)
+            && cursor.is_over(button)
+        {
+            shell.publish(on_close_all());
+            shell.capture_event();
+            return;
+        }
+
         let transformed_event = match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
                 let delta_x = match delta {
-                    mouse::ScrollDelta::Lines { y, .. } => {
-                        *y * VERTICAL_TO_HORIZONTAL_SCROLL_FACTOR
+                    mouse::ScrollDelta::Lines { y, .. } => *y * self.scroll_config.wheel_factor,
+                    // Prefer the horizontal component so diagonal trackpad
+                    // swipes don't drift; fall back to `y` for vertical wheels.
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x.abs() > f32::EPSILON {
+                            *x
+                        } else {
+                            *y
+                        }
                     }
-                    mouse::ScrollDelta::Pixels { x, y } => *x + *y,
                 };
                 if delta_x != 0.0
                     && cursor
@@ -727,40 +2925,248 @@ where
         let event_ref = transformed_event.as_ref().unwrap_or(event);
         let did_transform = transformed_event.is_some();
 
-        {
+        let captured_by_strip = {
             let mut element = self.wrapper_element();
             let tab_tree = ensure_child_tree(&mut state.children, &mut element);
+            if did_transform
+                && let Some(content_tree) = tab_tree
+                    .children
+                    .get_mut(0)
+                    .and_then(|scrollable| scrollable.children.get_mut(0))
+            {
+                content_tree
+                    .state
+                    .downcast_mut::<tab::TabBarContentState>()
+                    .just_scrolled = true;
+            }
             element.as_widget_mut().update(
                 tab_tree, event_ref, layout, cursor, renderer, clipboard, shell, viewport,
             );
+            let captured = shell.is_event_captured();
             if did_transform {
                 shell.capture_event();
             }
+            captured
+        };
+
+        let scrolling_count = self.scrolling_count();
+        let mut page_scroll = None;
+        let mut touch_scroll = None;
+        let mut snap_scroll = None;
+        let (scrolling_state, trailing_state) = content_states(state);
+
+        if let Some(trailing_state) = trailing_state {
+            // Lengths only differ before the trailing row has seen the
+            // current tabs; it catches up on its next update.
+            if self.tab_statuses.len() - scrolling_count == trailing_state.tab_statuses.len()
+                && self.tab_statuses[scrolling_count..] != trailing_state.tab_statuses[..]
+            {
+                self.tab_statuses[scrolling_count..].copy_from_slice(&trailing_state.tab_statuses);
+            }
+
+            if let Some(tooltip) = &mut trailing_state.tooltip
+                && let Some(pos) = cursor.position()
+            {
+                tooltip.cursor_pos = pos;
+            }
         }
 
-        if let Some(wrapper_tree) = state.children.get_mut(0) {
-            if let Some(content_tree) = wrapper_tree.children.get_mut(0) {
-                let content_state = content_tree.state.downcast_mut::<tab::TabBarContentState>();
+        if let Some(content_state) = scrolling_state {
+            if scrolling_count == content_state.tab_statuses.len()
+                && self.tab_statuses[..scrolling_count] != content_state.tab_statuses[..]
+            {
+                self.tab_statuses[..scrolling_count].copy_from_slice(&content_state.tab_statuses);
+            }
+
+            if let Some(tooltip) = &mut content_state.tooltip
+                && let Some(pos) = cursor.position()
+            {
+                tooltip.cursor_pos = pos;
+            }
+
+            if let Some(drag) = content_state.drag.as_mut()
+                && drag.is_dragging
+                && let Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. }) = event
+                && drag.overlay_pos != *position
+            {
+                drag.overlay_pos = *position;
+                shell.request_redraw();
+                if let Some(on_drag_move) = self.on_drag_move.as_ref() {
+                    shell.publish(on_drag_move(
+                        self.tab_indices[self.shown_index(drag.tab_index)].clone(),
+                        self.dragged_tab_position(drag, *position),
+                    ));
+                }
+            }
+
+            // Ctrl+Shift+Left/Right moves the focused tab one slot over.
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                modifiers,
+                ..
+            }) = event
+                && modifiers.control()
+                && modifiers.shift()
+                && !shell.is_event_captured()
+                && (self.on_reorder.is_some()
+                    || self.on_reorder_ids.is_some()
+                    || self.on_move.is_some())
+                && let Some(focused) = content_state.focused
+                && focused < scrolling_count
+                && let from = self.shown_index(focused)
+                && !self.tab_locked[from]
+            {
+                let group =
+                    tab::reorder_range(&self.sections, &self.tab_locked, from, scrolling_count);
+                // Reversed, the tab shown to the left comes next in the
+                // application's order.
+                let towards_start = match key {
+                    keyboard::key::Named::ArrowLeft => Some(!self.reversed),
+                    keyboard::key::Named::ArrowRight => Some(self.reversed),
+                    _ => None,
+                };
+                let to = towards_start.and_then(|towards_start| {
+                    if towards_start {
+                        from.checked_sub(1).filter(|&to| to >= group.start)
+                    } else {
+                        Some(from + 1).filter(|&to| to < group.end)
+                    }
+                });
+                if let Some(to) = to {
+                    if let Some(on_reorder) = self.on_reorder.as_ref() {
+                        shell.publish(on_reorder(from, to));
+                    }
+                    if let Some(on_move) = self.on_move.as_ref() {
+                        shell.publish(on_move(self.tab_indices[from].clone(), to));
+                    }
+                    if let Some(on_reorder_ids) = self.on_reorder_ids.as_ref() {
+                        let ids = tab::visual_order(self.tab_indices.len(), from, to)
+                            .into_iter()
+                            .map(|i| self.tab_indices[i].clone())
+                            .collect();
+                        shell.publish(on_reorder_ids(ids));
+                    }
+                    shell.capture_event();
+                    content_state.focused = Some(self.shown_index(to));
+                }
+            }
 
-                if self.tab_statuses != content_state.tab_statuses {
-                    self.tab_statuses.clone_from(&content_state.tab_statuses);
+            // Page Up/Down scroll the focused strip by a page.
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                modifiers,
+                ..
+            }) = event
+                && modifiers.is_empty()
+                && !shell.is_event_captured()
+                && content_state.focused.is_some()
+            {
+                let page = self.scroll_config.keyboard_step.unwrap_or_else(|| {
+                    layout
+                        .children()
+                        .next()
+                        .map_or(0.0, |scrollable| scrollable.bounds().width)
+                });
+                let delta = match key {
+                    keyboard::key::Named::PageUp => Some(-page),
+                    keyboard::key::Named::PageDown => Some(page),
+                    _ => None,
+                };
+                if let Some(delta) = delta {
+                    content_state.just_scrolled = true;
+                    page_scroll = Some(delta);
                 }
+            }
 
-                if let Some(tooltip) = &mut content_state.tooltip {
-                    if let Some(pos) = cursor.position() {
-                        tooltip.cursor_pos = pos;
+            // A finger swiping across the strip pans it, unless it is
+            // dragging a tab or the `Scrollable` is already panning itself.
+            match event {
+                Event::Touch(touch::Event::FingerPressed { id, position })
+                    if layout.bounds().contains(*position) =>
+                {
+                    content_state.touch_scroll = Some((*id, *position));
+                }
+                Event::Touch(touch::Event::FingerMoved { id, position })
+                    if !captured_by_strip && content_state.drag.is_none() =>
+                {
+                    if let Some((finger, last)) = content_state.touch_scroll.as_mut()
+                        && finger == id
+                    {
+                        let delta = last.x - position.x;
+                        *last = *position;
+                        if delta != 0.0 {
+                            content_state.just_scrolled = true;
+                            touch_scroll = Some(delta);
+                        }
                     }
                 }
+                Event::Touch(
+                    touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+                ) if content_state
+                    .touch_scroll
+                    .is_some_and(|(finger, _)| finger == *id) =>
+                {
+                    content_state.touch_scroll = None;
+                }
+                _ => {}
+            }
+
+            if self.scroll_snap
+                && (did_transform || page_scroll.is_some() || touch_scroll.is_some())
+            {
+                content_state.snap_after = Some(Instant::now());
+                content_state.snap_delta = None;
+            }
+            if let Some(delta) = content_state.snap_delta.take() {
+                content_state.just_scrolled = true;
+                snap_scroll = Some(delta);
+            }
+
+            // An auto-hiding scrollbar shows while the bar is hovered and for
+            // a moment after it is scrolled.
+            if self.scroll_mode == ScrollMode::FloatingAutoHide {
+                if did_transform
+                    || page_scroll.is_some()
+                    || touch_scroll.is_some()
+                    || snap_scroll.is_some()
+                {
+                    let now = Instant::now();
+                    content_state.scrolled_at = Some(now);
+                    shell.request_redraw();
+                    shell.request_redraw_at(now + SCROLLBAR_AUTO_HIDE_DELAY);
+                }
+                let hovered = cursor.is_over(layout.bounds());
+                if hovered != content_state.bar_hovered {
+                    content_state.bar_hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+        }
+
+        if let Some(delta) = page_scroll.or(touch_scroll) {
+            let mut element = self.wrapper_element();
+            let tab_tree = ensure_child_tree(&mut state.children, &mut element);
+            element.as_widget_mut().operate(
+                tab_tree,
+                layout,
+                renderer,
+                &mut scroll_by(self.scrollable_id(), AbsoluteOffset { x: delta, y: 0.0 }),
+            );
+            shell.capture_event();
+            shell.request_redraw();
+        }
 
-                if let Some(drag) = content_state.drag.as_mut() {
-                    if drag.is_dragging {
-                        if let Event::Mouse(mouse::Event::CursorMoved { position }) = event {
-                            drag.overlay_pos = *position;
-                            shell.request_redraw();
-                        }
-                    }
-                }
-            }
+        if let Some(delta) = snap_scroll {
+            let mut element = self.wrapper_element();
+            let tab_tree = ensure_child_tree(&mut state.children, &mut element);
+            element.as_widget_mut().operate(
+                tab_tree,
+                layout,
+                renderer,
+                &mut scroll_by(self.scrollable_id(), AbsoluteOffset { x: delta, y: 0.0 }),
+            );
+            shell.request_redraw();
         }
     }
 
@@ -772,6 +3178,12 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self
+            .close_all_bounds(layout)
+            .is_some_and(|button| cursor.is_over(button))
+        {
+            return mouse::Interaction::Pointer;
+        }
         let element = self.wrapper_element();
         element.as_widget().mouse_interaction(
             &state.children[0],
@@ -790,59 +3202,96 @@ where
         _viewport: &Rectangle,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        // Navigate the state tree: TabBar -> Scrollable -> Tab (content).
-        let content_state: &tab::TabBarContentState = state
-            .children
-            .get(0)?
-            .children
-            .get(0)?
-            .state
-            .downcast_ref::<tab::TabBarContentState>();
-
-        // Drag overlay takes priority over tooltip.
-        if let Some(drag) = &content_state.drag {
-            if drag.is_dragging {
-                if let Some(tab_label) = self.tab_labels.get(drag.tab_index) {
-                    let position = Point::new(
-                        drag.overlay_pos.x - drag.tab_offset_x,
-                        drag.overlay_pos.y - drag.tab_offset_y,
-                    );
+        let scrolling_count = self.scrolling_count();
+        let (scrolling_state, trailing_state) = content_states(state);
+        let content_state = scrolling_state?;
 
-                    let icon_font = self.font.unwrap_or(iced_fonts::CODICON_FONT);
-                    let text_font = self.text_font.unwrap_or_default();
-
-                    let drag_overlay = DragTabOverlay::new(
-                        tab_label.clone(),
-                        position,
-                        drag.tab_size,
-                        &self.class,
-                        (icon_font, self.icon_size),
-                        (text_font, self.text_size),
-                        self.close_size,
-                        self.close_spacing,
-                        self.icon_spacing,
-                        self.padding,
-                        self.tab_width,
-                        self.height,
-                        self.on_close.is_some(),
-                        self.position,
-                    );
+        // Drag overlay takes priority over tooltip. Without it, the dragged
+        // tab is drawn in the row and no tooltip shows during the drag.
+        if !self.drag_overlay && content_state.drag.as_ref().is_some_and(|d| d.is_dragging) {
+            return None;
+        }
+        if let Some(drag) = &content_state.drag
+            && drag.is_dragging
+            && let dragged = self.shown_index(drag.tab_index)
+            && let Some(tab_label) = self.tab_labels.get(dragged)
+        {
+            let position = self.dragged_tab_position(drag, drag.overlay_pos);
 
-                    return Some(overlay::Element::new(Box::new(drag_overlay)));
-                }
-            }
+            let icon_font = self.font.unwrap_or(iced_fonts::CODICON_FONT);
+            let text_font = self.text_font.unwrap_or_default();
+
+            let drag_overlay = DragTabOverlay::new(
+                tab_label,
+                position,
+                drag.tab_size,
+                &self.class,
+                (icon_font, self.icon_size),
+                (text_font, self.text_size),
+                self.close_size,
+                self.close_hit_area,
+                self.layout_size_offset,
+                &self.close_icon,
+                self.close_spacing,
+                self.position_icon_spacing(),
+                self.padding,
+                self.tab_width,
+                // The dragged tab already has its final height.
+                if self.auto_height {
+                    Length::Fill
+                } else {
+                    self.height
+                },
+                self.close_callback().is_some(),
+                self.position,
+                self.drag_overlay_style,
+                self.tab_backgrounds.get(dragged).copied().flatten(),
+                self.tab_close_colors.get(dragged).copied().flatten(),
+                drag.tab_index,
+                self.tab_indices.len(),
+            );
+
+            return Some(overlay::Element::new(Box::new(drag_overlay)));
         }
 
-        // Tooltip overlay (only when not dragging).
-        let (tooltip_index, cursor_pos) = {
+        // Tooltip overlay (only when not dragging). Trailing tabs keep their
+        // own tooltip state, indexed from the start of their group.
+        let (content_state, index_offset) = match trailing_state {
+            Some(trailing_state) if trailing_state.tooltip.is_some() => {
+                (trailing_state, scrolling_count)
+            }
+            _ => (content_state, 0),
+        };
+        let (tooltip_index, cursor_pos, is_close, tab_bounds) = {
             let ts = content_state.tooltip.as_ref()?;
-            if ts.hover_start.elapsed() < self.tooltip_delay {
+            if !self.tooltip_delay.is_zero() && ts.hover_start.elapsed() < self.tooltip_delay {
                 return None;
             }
-            (ts.tab_index, ts.cursor_pos)
+            (
+                self.shown_index(ts.tab_index + index_offset),
+                ts.cursor_pos,
+                ts.is_close,
+                ts.tab_bounds,
+            )
         };
 
-        let text = self.tab_tooltips.get(tooltip_index)?.as_ref()?;
+        // The cursor sits on the close button for close tooltips, so the
+        // cursor-anchored placement below already puts it next to the X.
+        let body = if is_close {
+            TooltipBody::Text(self.close_tooltip.as_deref()?)
+        } else if let Some(TooltipContent::Element(view)) = self.tab_tooltips.get(tooltip_index)? {
+            TooltipBody::element(view())
+        } else {
+            TooltipBody::Text(tab::tooltip_text(
+                self.tab_tooltips
+                    .get(tooltip_index)?
+                    .as_ref()
+                    .and_then(TooltipContent::text),
+                self.tab_names.get(tooltip_index)?.as_ref(),
+                self.tab_labels.get(tooltip_index)?,
+                self.auto_tooltip || content_state.collapsed.is_some(),
+            )?)
+        };
 
         let bar_bounds = layout.bounds();
         let position = Point::new(
@@ -850,13 +3299,19 @@ where
             bar_bounds.y + bar_bounds.height + translation.y + 4.0,
         );
 
-        let tooltip = TooltipOverlay::new(
-            text.as_str(),
+        let mut tooltip = TooltipOverlay::new(
+            body,
             position,
-            crate::TooltipStyle::default(),
+            content_state.tooltip_style.get(),
             self.text_size.min(14.0),
             self.text_font.unwrap_or_default(),
+            &mut content_state.tooltip_measurement,
         );
+        if let TooltipAnchor::Tab { gap } = self.tooltip_anchor
+            && !is_close
+        {
+            tooltip = tooltip.anchored_to(tab_bounds + translation, gap);
+        }
 
         Some(overlay::Element::new(Box::new(tooltip)))
     }
@@ -868,12 +3323,983 @@ where
     Renderer: 'a
         + renderer::Renderer
         + iced::advanced::text::Renderer<Font = Font>
-        + iced::advanced::svg::Renderer,
+        + tab::CloseIconRenderer,
     Theme: 'a + Catalog + text::Catalog + scrollable::Catalog + container::Catalog,
     Message: 'a,
-    TabId: 'a + Eq + Clone,
+    TabId: 'static + Eq + Clone,
 {
     fn from(tab_bar: TabBar<'a, Message, TabId, Theme, Renderer>) -> Self {
         Element::new(tab_bar)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::clipboard;
+    use iced::{Theme, window};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Select(usize),
+        Reselect(usize),
+        Close(usize),
+        Empty,
+        Move(usize, usize),
+        Preview(usize, usize),
+        DragMove(usize, Point),
+        Bounds(usize, Rectangle),
+    }
+
+    type Bar = TabBar<'static, Message, usize, Theme, ()>;
+
+    /// Builds a bar with a text tab for each id.
+    fn bar(ids: &[usize]) -> Bar {
+        ids.iter().fold(TabBar::new(Message::Select), |bar, &id| {
+            bar.push(id, TabLabel::Text(format!("Tab {id}")))
+        })
+    }
+
+    /// A [`TabBar`] laid out with the null renderer and fed events the way
+    /// the runtime would.
+    struct Harness {
+        element: Element<'static, Message, Theme, ()>,
+        tree: Tree,
+        node: Node,
+        size: Size,
+        /// The redraw requested while handling the last event.
+        redraw: window::RedrawRequest,
+    }
+
+    impl Harness {
+        fn new(bar: Bar) -> Self {
+            Self::with_size(bar, Size::new(1000.0, 100.0))
+        }
+
+        fn with_size(bar: Bar, size: Size) -> Self {
+            let element = Element::new(bar);
+            let tree = Tree::new(element.as_widget());
+            let mut harness = Self {
+                element,
+                tree,
+                node: Node::new(Size::ZERO),
+                size,
+                redraw: window::RedrawRequest::Wait,
+            };
+            harness.relayout();
+            harness
+        }
+
+        /// Replaces the bar, as the application does after handling a
+        /// message.
+        fn rebuild(&mut self, bar: Bar) {
+            self.element = Element::new(bar);
+            self.tree.diff(self.element.as_widget_mut());
+            self.relayout();
+        }
+
+        fn relayout(&mut self) {
+            self.node = self.element.as_widget_mut().layout(
+                &mut self.tree,
+                &(),
+                &Limits::new(Size::ZERO, self.size),
+            );
+        }
+
+        /// Sends `event` with the cursor at `cursor` and returns the published
+        /// messages.
+        fn event(&mut self, event: Event, cursor: Point) -> Vec<Message> {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+            self.element.as_widget_mut().update(
+                &mut self.tree,
+                &event,
+                Layout::new(&self.node),
+                Cursor::Available(cursor),
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(self.size),
+            );
+            self.redraw = shell.redraw_request();
+            messages
+        }
+
+        fn draw(&self, cursor: Point) {
+            self.element.as_widget().draw(
+                &self.tree,
+                &mut (),
+                &Theme::Light,
+                &renderer::Style {
+                    text_color: Color::BLACK,
+                },
+                Layout::new(&self.node),
+                Cursor::Available(cursor),
+                &Rectangle::with_size(self.size),
+            );
+        }
+
+        fn has_overlay(&mut self) -> bool {
+            self.element
+                .as_widget_mut()
+                .overlay(
+                    &mut self.tree,
+                    Layout::new(&self.node),
+                    &(),
+                    &Rectangle::with_size(self.size),
+                    Vector::ZERO,
+                )
+                .is_some()
+        }
+
+        /// Scrolls the strip with the cursor at `at`.
+        fn wheel(&mut self, delta: mouse::ScrollDelta, at: Point) -> Vec<Message> {
+            self.event(Event::Mouse(mouse::Event::WheelScrolled { delta }), at)
+        }
+
+        /// Returns how far the strip is scrolled.
+        fn scroll_offset(&mut self) -> f32 {
+            let mut scroll = ScrollTranslation {
+                id: Id::new(SCROLLABLE_ID),
+                translation: Vector::ZERO,
+            };
+            self.element.as_widget_mut().operate(
+                &mut self.tree,
+                Layout::new(&self.node),
+                &(),
+                &mut scroll,
+            );
+            scroll.translation.x
+        }
+
+        fn press(&mut self, at: Point) -> Vec<Message> {
+            self.event(
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                at,
+            )
+        }
+
+        fn touch(&mut self, at: Point) -> Vec<Message> {
+            self.event(
+                Event::Touch(touch::Event::FingerPressed {
+                    id: touch::Finger(0),
+                    position: at,
+                }),
+                at,
+            )
+        }
+
+        fn touch_move(&mut self, to: Point) -> Vec<Message> {
+            self.event(
+                Event::Touch(touch::Event::FingerMoved {
+                    id: touch::Finger(0),
+                    position: to,
+                }),
+                to,
+            )
+        }
+
+        fn move_to(&mut self, to: Point) -> Vec<Message> {
+            self.event(Event::Mouse(mouse::Event::CursorMoved { position: to }), to)
+        }
+
+        fn release(&mut self, at: Point) -> Vec<Message> {
+            self.event(
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                at,
+            )
+        }
+
+        fn key_press(
+            &mut self,
+            key: keyboard::Key,
+            modifiers: keyboard::Modifiers,
+        ) -> Vec<Message> {
+            let event = Event::Keyboard(keyboard::Event::KeyPressed {
+                modified_key: key.clone(),
+                key,
+                physical_key: keyboard::key::Physical::Unidentified(
+                    keyboard::key::NativeCode::Unidentified,
+                ),
+                location: keyboard::Location::Standard,
+                modifiers,
+                text: None,
+                repeat: false,
+            });
+            self.event(event, Point::ORIGIN)
+        }
+
+        /// Runs [`tab_at`] for `point` on the bar with the id `"bar"`.
+        fn tab_at(&mut self, point: Point) -> Option<usize> {
+            let mut tab_at = tab_at("bar", point);
+            self.element.as_widget_mut().operate(
+                &mut self.tree,
+                Layout::new(&self.node),
+                &(),
+                &mut operation::black_box(&mut tab_at),
+            );
+            match tab_at.finish() {
+                Outcome::Some(tab) => tab,
+                _ => None,
+            }
+        }
+
+        /// Returns the layouts of the scrolling tabs, in visual order.
+        fn tab_layouts(&self) -> Vec<Layout<'_>> {
+            Layout::new(&self.node)
+                .children()
+                .next()
+                .and_then(|scrollable| scrollable.children().next())
+                .map(|content| content.children().collect())
+                .unwrap_or_default()
+        }
+
+        /// Returns the bounds of the scrolling tabs, in visual order.
+        fn tab_bounds(&self) -> Vec<Rectangle> {
+            self.tab_layouts().iter().map(Layout::bounds).collect()
+        }
+
+        /// Returns the center of the close button of the tab at `index`.
+        fn close_button(&self, index: usize) -> Point {
+            tab::close_layout(self.tab_layouts()[index])
+                .expect("tab has a close button")
+                .bounds()
+                .center()
+        }
+
+        fn content_state(&mut self) -> &mut tab::TabBarContentState {
+            content_states(&mut self.tree)
+                .0
+                .expect("bar has a scrolling group")
+        }
+    }
+
+    #[test]
+    fn tab_bounds_are_reported_again_only_when_they_change() {
+        let bounds_of = |messages: Vec<Message>| {
+            messages
+                .into_iter()
+                .filter_map(|message| match message {
+                    Message::Bounds(id, _) => Some(id),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        let idle = Event::Mouse(mouse::Event::CursorLeft);
+
+        let mut harness = Harness::new(bar(&[0, 1, 2]).on_tab_bounds(Message::Bounds));
+        assert_eq!(
+            bounds_of(harness.event(idle.clone(), Point::ORIGIN)),
+            [0, 1, 2]
+        );
+        assert_eq!(bounds_of(harness.event(idle.clone(), Point::ORIGIN)), []);
+
+        // Laying the same tabs out again reports nothing new.
+        harness.rebuild(bar(&[0, 1, 2]).on_tab_bounds(Message::Bounds));
+        assert_eq!(bounds_of(harness.event(idle.clone(), Point::ORIGIN)), []);
+
+        // Tabs that swap places keep their bounds but change their ids.
+        harness.rebuild(bar(&[1, 0, 2]).on_tab_bounds(Message::Bounds));
+        assert_eq!(bounds_of(harness.event(idle, Point::ORIGIN)), [1, 0]);
+    }
+
+    fn closable(ids: &[usize]) -> Bar {
+        bar(ids)
+            .on_close(Message::Close)
+            .close_animation(Duration::from_millis(120))
+    }
+
+    #[test]
+    fn close_animation_starts_once_the_closed_tab_is_removed() {
+        let mut harness = Harness::new(closable(&[0, 1, 2]));
+        let close = harness.close_button(1);
+        assert_eq!(harness.press(close), vec![Message::Close(1)]);
+
+        harness.rebuild(closable(&[0, 2]));
+        let _ = harness.move_to(Point::ORIGIN);
+        let closing = harness.content_state().closing.as_ref();
+        assert!(closing.is_some_and(|closing| closing.removed.is_some()));
+    }
+
+    #[test]
+    fn close_animation_ignores_other_tabs_being_removed() {
+        let mut harness = Harness::new(closable(&[0, 1, 2]));
+        let close = harness.close_button(1);
+        let _ = harness.press(close);
+
+        // The application removes a different tab instead.
+        harness.rebuild(closable(&[1, 2]));
+        let _ = harness.move_to(Point::ORIGIN);
+        let closing = harness.content_state().closing.as_ref();
+        assert!(closing.is_some_and(|closing| closing.removed.is_none()));
+    }
+
+    #[test]
+    fn switching_to_wrap_lays_the_tabs_out_again() {
+        let ids: Vec<usize> = (0..10).collect();
+        let collapse = OverflowBehavior::CollapseToIcons {
+            keep_active_text: false,
+        };
+        let mut harness = Harness::with_size(
+            bar(&ids).overflow_behavior(collapse),
+            Size::new(40.0, 100.0),
+        );
+        assert!(harness.tab_bounds().iter().all(|tab| tab.y == 0.0));
+
+        harness.rebuild(bar(&ids).overflow_behavior(OverflowBehavior::Wrap));
+        assert!(harness.tab_bounds().iter().any(|tab| tab.y > 0.0));
+    }
+
+    #[test]
+    fn on_move_follows_the_dragged_tab_when_tabs_change_mid_drag() {
+        let mut harness = Harness::new(bar(&[0, 1, 2]).on_move(Message::Move));
+        let tabs = harness.tab_bounds();
+        let last = tabs[2];
+
+        let _ = harness.press(tabs[1].center());
+        let _ = harness.move_to(Point::new(last.x + last.width - 1.0, last.center_y()));
+
+        // The application inserts a tab in front of the dragged one.
+        harness.rebuild(bar(&[9, 0, 1, 2]).on_move(Message::Move));
+        let last = harness.tab_bounds()[3];
+        let drop = Point::new(last.x + last.width - 1.0, last.center_y());
+        let _ = harness.move_to(drop);
+
+        assert_eq!(harness.release(drop), vec![Message::Move(1, 3)]);
+    }
+
+    #[test]
+    fn drag_is_cancelled_when_the_dragged_tab_is_removed() {
+        let mut harness = Harness::new(bar(&[0, 1, 2, 3]).on_move(Message::Move));
+        let tabs = harness.tab_bounds();
+        let last = tabs[3];
+
+        let _ = harness.press(tabs[1].center());
+        let _ = harness.move_to(Point::new(last.x + last.width - 1.0, last.center_y()));
+
+        harness.rebuild(bar(&[0, 2, 3]).on_move(Message::Move));
+        let last = harness.tab_bounds()[2];
+        let drop = Point::new(last.x + last.width - 1.0, last.center_y());
+        let _ = harness.move_to(drop);
+
+        assert_eq!(harness.release(drop), vec![]);
+    }
+
+    fn reselect(behavior: ReselectBehavior<Message, usize>) -> Vec<Message> {
+        let mut harness = Harness::new(
+            bar(&[0, 1, 2])
+                .set_active_tab(&1)
+                .reselect_behavior(behavior),
+        );
+        let active = harness.tab_bounds()[1].center();
+        let mut messages = harness.press(active);
+        messages.extend(harness.release(active));
+        messages
+    }
+
+    #[test]
+    fn reselecting_the_active_tab_emits_on_select_by_default() {
+        assert_eq!(reselect(ReselectBehavior::Emit), vec![Message::Select(1)]);
+    }
+
+    #[test]
+    fn reselecting_the_active_tab_can_be_suppressed() {
+        assert_eq!(reselect(ReselectBehavior::Suppress), vec![]);
+    }
+
+    #[test]
+    fn reselecting_the_active_tab_can_emit_its_own_message() {
+        assert_eq!(
+            reselect(ReselectBehavior::emit_reselect(Message::Reselect)),
+            vec![Message::Reselect(1)]
+        );
+    }
+
+    fn delete() -> keyboard::Key {
+        keyboard::Key::Named(keyboard::key::Named::Delete)
+    }
+
+    fn ctrl_w() -> (keyboard::Key, keyboard::Modifiers) {
+        (
+            keyboard::Key::Character("w".into()),
+            keyboard::Modifiers::COMMAND,
+        )
+    }
+
+    #[test]
+    fn delete_closes_the_focused_tab_only_when_enabled() {
+        let none = keyboard::Modifiers::empty();
+        let mut harness = Harness::new(bar(&[0, 1, 2]).on_close(Message::Close));
+        harness.content_state().focused = Some(1);
+        assert_eq!(harness.key_press(delete(), none), vec![]);
+        let (w, ctrl) = ctrl_w();
+        assert_eq!(harness.key_press(w, ctrl), vec![Message::Close(1)]);
+
+        let mut harness = Harness::new(
+            bar(&[0, 1, 2])
+                .on_close(Message::Close)
+                .close_on_delete(true),
+        );
+        harness.content_state().focused = Some(1);
+        assert_eq!(harness.key_press(delete(), none), vec![Message::Close(1)]);
+    }
+
+    #[test]
+    fn declined_keyboard_close_keeps_focus() {
+        let requesting = || bar(&[0, 1, 2]).on_close_request(Message::Close);
+        let mut harness = Harness::new(requesting());
+        harness.content_state().focused = Some(2);
+        let (w, ctrl) = ctrl_w();
+        assert_eq!(harness.key_press(w, ctrl), vec![Message::Close(2)]);
+
+        // The application declines: the tabs stay as they were.
+        harness.rebuild(requesting());
+        let _ = harness.move_to(Point::ORIGIN);
+        assert_eq!(harness.content_state().focused, Some(2));
+
+        // Once the last tab is really gone, the new last tab has focus.
+        harness.rebuild(bar(&[0, 1]).on_close_request(Message::Close));
+        let _ = harness.move_to(Point::ORIGIN);
+        assert_eq!(harness.content_state().focused, Some(1));
+    }
+
+    #[test]
+    fn tabs_drops_the_sections_and_close_colors_of_the_previous_tabs() {
+        let bar = bar(&[0])
+            .push_section("Pinned")
+            .push(1, TabLabel::Text("Tab 1".to_owned()))
+            .set_close_color(&1, Color::BLACK)
+            .tabs([(2, TabLabel::Text("Tab 2".to_owned()))]);
+
+        assert!(bar.sections.is_empty());
+        assert_eq!(bar.tab_close_colors, vec![None]);
+    }
+
+    #[test]
+    fn tab_at_reports_the_id_of_the_tab_under_the_point() {
+        let mut harness = Harness::new(bar(&[0, 1, 2]).id("bar").reversed(true).spacing(10.0));
+        let tabs = harness.tab_bounds();
+
+        assert_eq!(harness.tab_at(tabs[0].center()), Some(2));
+        assert_eq!(harness.tab_at(tabs[2].center()), Some(0));
+        let gap = Point::new(tabs[0].x + tabs[0].width + 5.0, tabs[0].center_y());
+        assert_eq!(harness.tab_at(gap), None);
+    }
+
+    /// Starts dragging the first tab to the right end of the bar, then lets
+    /// the application replace the tabs with `ids` mid-drag.
+    fn drag_while_tabs_become(ids: &[usize]) -> Vec<Message> {
+        let mut harness = Harness::new(bar(&[0, 1]).on_move(Message::Move));
+        let tabs = harness.tab_bounds();
+        let _ = harness.press(tabs[0].center());
+        let end = Point::new(tabs[1].x + tabs[1].width - 1.0, tabs[1].center_y());
+        let _ = harness.move_to(end);
+        harness.draw(end);
+
+        harness.rebuild(bar(ids).on_move(Message::Move));
+        let mut messages = harness.move_to(end);
+        harness.draw(end);
+        messages.extend(harness.release(end));
+        harness.draw(end);
+        messages
+    }
+
+    #[test]
+    fn drag_in_a_strip_left_with_only_the_dragged_tab_does_nothing() {
+        assert_eq!(drag_while_tabs_become(&[0]), vec![]);
+    }
+
+    #[test]
+    fn drag_in_a_strip_left_empty_does_nothing() {
+        assert_eq!(drag_while_tabs_become(&[]), vec![]);
+    }
+
+    #[test]
+    fn dragging_the_only_tab_does_nothing() {
+        let mut harness = Harness::new(bar(&[0]).on_move(Message::Move));
+        let tab = harness.tab_bounds()[0];
+        let _ = harness.press(tab.center());
+        let end = Point::new(tab.x + tab.width + 50.0, tab.center_y());
+        let _ = harness.move_to(end);
+        harness.draw(end);
+        assert_eq!(harness.release(end), vec![]);
+    }
+
+    #[test]
+    fn no_tab_is_active_after_clear_active() {
+        let statuses = |bar: Bar| {
+            let mut harness = Harness::new(bar);
+            let away = Point::new(-10.0, -10.0);
+            let _ = harness.move_to(away);
+            harness
+                .content_state()
+                .tab_statuses
+                .iter()
+                .map(|(status, _)| *status)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            statuses(bar(&[0, 1, 2]).set_active_tab(&1))[1],
+            Some(Status::Active)
+        );
+        assert_eq!(
+            statuses(bar(&[0, 1, 2]).set_active_tab(&1).clear_active()),
+            vec![Some(Status::Inactive); 3]
+        );
+    }
+
+    #[test]
+    fn max_width_caps_a_filling_bar() {
+        let harness = Harness::with_size(
+            bar(&[0, 1, 2]).width(Length::Fill).max_width(400.0),
+            Size::new(1000.0, 100.0),
+        );
+        assert_eq!(harness.node.size().width, 400.0);
+    }
+
+    #[test]
+    fn no_drag_overlay_is_produced_when_disabled() {
+        let dragging = |bar: Bar| {
+            let mut harness = Harness::new(bar);
+            let tabs = harness.tab_bounds();
+            let _ = harness.press(tabs[0].center());
+            let _ = harness.move_to(tabs[2].center());
+            assert!(harness.content_state().drag.is_some());
+            harness.has_overlay()
+        };
+
+        assert!(dragging(bar(&[0, 1, 2]).on_move(Message::Move)));
+        assert!(!dragging(
+            bar(&[0, 1, 2]).on_move(Message::Move).drag_overlay(false)
+        ));
+    }
+
+    #[test]
+    fn clicking_the_visually_first_tab_of_a_reversed_bar_selects_the_last_id() {
+        let mut harness = Harness::new(bar(&[10, 11, 12]).reversed(true));
+        let first = harness.tab_bounds()[0].center();
+        let mut messages = harness.press(first);
+        messages.extend(harness.release(first));
+        assert_eq!(messages, vec![Message::Select(12)]);
+    }
+
+    #[test]
+    fn dragging_in_a_reversed_bar_moves_the_tab_in_application_order() {
+        let mut harness = Harness::new(bar(&[0, 1, 2]).reversed(true).on_move(Message::Move));
+        let tabs = harness.tab_bounds();
+        let last = tabs[2];
+        let drop = Point::new(last.x + last.width - 1.0, last.center_y());
+
+        let _ = harness.press(tabs[0].center());
+        let _ = harness.move_to(drop);
+        harness.draw(drop);
+
+        assert_eq!(harness.release(drop), vec![Message::Move(2, 0)]);
+    }
+
+    #[test]
+    fn keyboard_reorder_in_a_reversed_bar_follows_the_arrows_within_a_section() {
+        let label = |id: usize| TabLabel::Text(format!("Tab {id}"));
+        // Shown as 3 2 | 1 0.
+        let mut harness = Harness::new(
+            bar(&[0, 1])
+                .push_section("Section")
+                .push(2, label(2))
+                .push(3, label(3))
+                .reversed(true)
+                .on_move(Message::Move),
+        );
+        let ctrl_shift = keyboard::Modifiers::CTRL | keyboard::Modifiers::SHIFT;
+        let arrow = |named| keyboard::Key::Named(named);
+        harness.content_state().focused = Some(1);
+
+        let right = harness.key_press(arrow(keyboard::key::Named::ArrowRight), ctrl_shift);
+        assert_eq!(right, vec![]);
+        let left = harness.key_press(arrow(keyboard::key::Named::ArrowLeft), ctrl_shift);
+        assert_eq!(left, vec![Message::Move(2, 3)]);
+        assert_eq!(harness.content_state().focused, Some(0));
+    }
+
+    #[test]
+    fn auto_height_fits_both_stacked_lines() {
+        // The null renderer measures text as empty, so the stacked lines are
+        // only as tall as the padding and the spacing between them.
+        let stacked = bar(&[])
+            .push(0, TabLabel::IconText('a', "Tab 0".to_owned()))
+            .set_position(Position::Top)
+            .padding(5.0)
+            .icon_text_spacing(0.0, 20.0)
+            .height(Length::Fixed(10.0))
+            .auto_height(true);
+        let harness = Harness::new(stacked);
+        let tab = harness.tab_layouts()[0];
+
+        fn descendants(layout: Layout<'_>) -> Vec<Rectangle> {
+            layout
+                .children()
+                .flat_map(|child| {
+                    let mut bounds = vec![child.bounds()];
+                    bounds.extend(descendants(child));
+                    bounds
+                })
+                .collect()
+        }
+        let lines = descendants(tab);
+        assert!(tab.bounds().height >= 30.0);
+        assert!(lines.iter().all(|line| {
+            line.y >= tab.bounds().y - 0.5
+                && line.y + line.height <= tab.bounds().y + tab.bounds().height + 0.5
+        }));
+        assert!(harness.node.size().height >= tab.bounds().height);
+    }
+
+    #[test]
+    fn diagonal_pixel_scroll_follows_the_horizontal_delta() {
+        let ids: Vec<usize> = (0..20).collect();
+        let scrolled = |x, y| {
+            let mut harness = Harness::with_size(bar(&ids).padding(20.0), Size::new(200.0, 100.0));
+            let at = harness.tab_bounds()[0].center();
+            let _ = harness.wheel(mouse::ScrollDelta::Pixels { x, y }, at);
+            harness.scroll_offset()
+        };
+
+        assert_eq!(scrolled(-30.0, -20.0), 30.0);
+        assert_eq!(scrolled(0.0, -20.0), 20.0);
+    }
+
+    #[test]
+    fn closing_a_middle_tab_keeps_the_scroll_offset() {
+        let wide = |ids: &[usize]| bar(ids).padding(20.0).on_close(Message::Close);
+        let ids: Vec<usize> = (0..20).collect();
+        let mut harness = Harness::with_size(wide(&ids), Size::new(200.0, 100.0));
+        let at = harness.tab_bounds()[0].center();
+        let _ = harness.wheel(mouse::ScrollDelta::Pixels { x: -100.0, y: 0.0 }, at);
+        assert_eq!(harness.scroll_offset(), 100.0);
+
+        let remaining: Vec<usize> = ids.iter().copied().filter(|&id| id != 10).collect();
+        harness.rebuild(wide(&remaining));
+        assert_eq!(harness.scroll_offset(), 100.0);
+
+        // Scrolled to the end, the offset shrinks with the content.
+        let _ = harness.wheel(
+            mouse::ScrollDelta::Pixels {
+                x: -10_000.0,
+                y: 0.0,
+            },
+            at,
+        );
+        let end = harness.scroll_offset();
+        harness.rebuild(wide(&remaining[1..]));
+        let tab_width = harness.tab_bounds()[0].width;
+        assert!(harness.scroll_offset() < end);
+        assert!(harness.scroll_offset() >= end - tab_width - 1.0);
+    }
+
+    #[test]
+    fn position_helpers_cover_every_variant() {
+        let helpers = |position: Position| (position.is_vertical(), position.is_icon_first());
+        assert_eq!(helpers(Position::Top), (true, true));
+        assert_eq!(helpers(Position::Right), (false, false));
+        assert_eq!(helpers(Position::Bottom), (true, false));
+        assert_eq!(helpers(Position::Left), (false, true));
+    }
+
+    #[test]
+    fn closing_the_only_tab_also_publishes_on_empty() {
+        let mut harness = Harness::new(
+            bar(&[0])
+                .on_close(Message::Close)
+                .on_empty(|| Message::Empty),
+        );
+        let close = harness.close_button(0);
+        assert_eq!(
+            harness.press(close),
+            vec![Message::Close(0), Message::Empty]
+        );
+
+        let mut harness = Harness::new(
+            bar(&[0, 1])
+                .on_close(Message::Close)
+                .on_empty(|| Message::Empty),
+        );
+        let close = harness.close_button(0);
+        assert_eq!(harness.press(close), vec![Message::Close(0)]);
+    }
+
+    fn with_tooltip(delay: Duration) -> Bar {
+        TabBar::new(Message::Select)
+            .push_with_tooltip(0, TabLabel::Text("Tab 0".to_owned()), "First")
+            .tooltip_delay(delay)
+    }
+
+    #[test]
+    fn tooltip_without_delay_shows_on_the_first_hover() {
+        let mut harness = Harness::new(with_tooltip(Duration::ZERO));
+        let _ = harness.move_to(harness.tab_bounds()[0].center());
+        assert!(harness.has_overlay());
+    }
+
+    #[test]
+    fn tooltip_with_a_long_delay_schedules_its_own_redraw() {
+        let delay = Duration::from_secs(10);
+        let mut harness = Harness::new(with_tooltip(delay));
+        let hovered = harness.tab_bounds()[0].center();
+        let _ = harness.move_to(hovered);
+        assert!(!harness.has_overlay());
+
+        // The next frame asks to be redrawn when the delay is over, without
+        // the cursor moving again.
+        let frame = Event::Window(window::Event::RedrawRequested(Instant::now()));
+        let _ = harness.event(frame, hovered);
+        let window::RedrawRequest::At(due) = harness.redraw else {
+            panic!(
+                "expected a redraw at the end of the delay, got {:?}",
+                harness.redraw
+            );
+        };
+        assert!(due > Instant::now() + delay - Duration::from_secs(1));
+        assert!(!harness.has_overlay());
+    }
+
+    #[test]
+    fn tooltip_with_a_huge_delay_never_shows() {
+        let mut harness = Harness::new(with_tooltip(Duration::MAX));
+        let hovered = harness.tab_bounds()[0].center();
+        let _ = harness.move_to(hovered);
+        let _ = harness.move_to(hovered);
+        assert_eq!(harness.redraw, window::RedrawRequest::Wait);
+        assert!(!harness.has_overlay());
+    }
+
+    #[test]
+    fn removing_tabs_mid_drag_does_not_panic() {
+        let mut harness = Harness::new(bar(&[0, 1, 2, 3]).on_move(Message::Move));
+        let tabs = harness.tab_bounds();
+        let _ = harness.press(tabs[3].center());
+        let _ = harness.move_to(tabs[1].center());
+        harness.draw(tabs[1].center());
+
+        // The dragged tab is the last one, past the end of the new statuses.
+        harness.rebuild(bar(&[0, 1]).on_move(Message::Move));
+        harness.draw(tabs[1].center());
+        let _ = harness.move_to(tabs[0].center());
+        harness.draw(tabs[0].center());
+        assert!(harness.content_state().drag.is_none());
+        assert_eq!(harness.release(tabs[0].center()), vec![]);
+    }
+
+    #[test]
+    fn touching_the_close_button_closes_the_tab() {
+        let mut harness = Harness::new(bar(&[0, 1, 2]).on_close(Message::Close));
+        let close = harness.close_button(1);
+        assert_eq!(harness.touch(close), vec![Message::Close(1)]);
+
+        let mut harness = Harness::new(bar(&[0, 1, 2]).on_close(Message::Close));
+        let body = Point::new(harness.tab_bounds()[1].x + 2.0, harness.close_button(1).y);
+        assert_eq!(harness.touch(body), vec![Message::Select(1)]);
+    }
+
+    #[test]
+    fn removing_tabs_keeps_the_active_index_in_range() {
+        let lengths = |bar: &Bar| {
+            [
+                bar.tab_labels.len(),
+                bar.tab_indices.len(),
+                bar.tab_statuses.len(),
+                bar.tab_tooltips.len(),
+                bar.tab_names.len(),
+                bar.tab_backgrounds.len(),
+                bar.tab_close_colors.len(),
+                bar.tab_locked.len(),
+            ]
+        };
+
+        // The active tab itself: its right neighbor takes its place.
+        let removed = bar(&[0, 1, 2]).set_active_tab(&1).remove(&1);
+        assert_eq!(removed.get_active_tab_idx(), Some(1));
+        assert_eq!(removed.get_active_tab_id(), Some(&2));
+        assert_eq!(lengths(&removed), [2; 8]);
+
+        // The active last tab: the new last tab takes its place.
+        let removed = bar(&[0, 1, 2]).set_active_tab(&2).remove(&2);
+        assert_eq!(removed.get_active_tab_idx(), Some(1));
+
+        // A tab before the active one shifts it left.
+        let removed = bar(&[0, 1, 2]).set_active_tab(&2).remove(&0);
+        assert_eq!(removed.get_active_tab_idx(), Some(1));
+        assert_eq!(removed.get_active_tab_id(), Some(&2));
+
+        // Unknown ids are ignored.
+        let removed = bar(&[0, 1, 2]).set_active_tab(&2).remove(&7);
+        assert_eq!(lengths(&removed), [3; 8]);
+    }
+
+    #[test]
+    fn layout_size_offset_pads_the_close_icon() {
+        let close_size = |bar: Bar| {
+            let harness = Harness::new(
+                bar.on_close(Message::Close)
+                    .close_size(10.0)
+                    .close_hit_area(1.0),
+            );
+            tab::close_layout(harness.tab_layouts()[0])
+                .expect("tab has a close button")
+                .bounds()
+                .size()
+        };
+
+        // The null renderer measures text as empty, so the close icon is the
+        // only label part the offset shows on.
+        assert_eq!(close_size(bar(&[0])), Size::new(11.0, 11.0));
+        assert_eq!(
+            close_size(bar(&[0]).layout_size_offset(0.0)),
+            Size::new(10.0, 10.0)
+        );
+        assert_eq!(
+            close_size(bar(&[0]).layout_size_offset(4.0)),
+            Size::new(14.0, 14.0)
+        );
+    }
+
+    #[test]
+    fn escape_cancels_a_drag_without_reordering() {
+        let mut harness = Harness::new(bar(&[0, 1, 2]).on_reorder(Message::Move));
+        let tabs = harness.tab_bounds();
+        let end = Point::new(tabs[2].x + tabs[2].width - 1.0, tabs[2].center_y());
+        let _ = harness.press(tabs[0].center());
+        let _ = harness.move_to(end);
+        assert!(harness.content_state().drag.is_some());
+
+        let escape = keyboard::Key::Named(keyboard::key::Named::Escape);
+        assert_eq!(
+            harness.key_press(escape, keyboard::Modifiers::empty()),
+            vec![]
+        );
+        assert!(harness.content_state().drag.is_none());
+        assert_eq!(harness.release(end), vec![]);
+    }
+
+    #[test]
+    fn tabs_cannot_be_dragged_past_a_locked_tab() {
+        let locked = || bar(&[0, 1, 2, 3]).locked(&[2]).on_reorder(Message::Move);
+        let mut harness = Harness::new(locked());
+        let tabs = harness.tab_bounds();
+        let end = Point::new(tabs[3].x + tabs[3].width - 1.0, tabs[3].center_y());
+
+        // Tab 0 stops in front of the locked tab.
+        let _ = harness.press(tabs[0].center());
+        let _ = harness.move_to(end);
+        assert_eq!(harness.release(end), vec![Message::Move(0, 1)]);
+
+        // Tab 3 can't pass it going left either.
+        let mut harness = Harness::new(locked());
+        let _ = harness.press(tabs[3].center());
+        let _ = harness.move_to(Point::new(tabs[0].x + 1.0, tabs[0].center_y()));
+        assert_eq!(harness.release(tabs[0].center()), vec![]);
+    }
+
+    #[test]
+    fn a_locked_tab_is_selected_but_not_dragged() {
+        let mut harness = Harness::new(bar(&[0, 1, 2]).locked(&[1]).on_reorder(Message::Move));
+        let tabs = harness.tab_bounds();
+        assert_eq!(harness.press(tabs[1].center()), vec![Message::Select(1)]);
+        let _ = harness.move_to(tabs[2].center());
+        assert!(harness.content_state().drag.is_none());
+        assert_eq!(harness.release(tabs[2].center()), vec![]);
+    }
+
+    #[test]
+    fn returning_a_dragged_tab_to_its_slot_does_not_reorder() {
+        let ids = [0, 1, 2, 3, 4];
+        for away in [0, 4] {
+            // The preview keeps the drop target up to date during the drag.
+            let mut harness = Harness::new(
+                bar(&ids)
+                    .spacing(10.0)
+                    .on_reorder(Message::Move)
+                    .on_reorder_preview(Message::Preview),
+            );
+            let tabs = harness.tab_bounds();
+            let _ = harness.press(tabs[2].center());
+            let _ = harness.move_to(tabs[away].center());
+            let target = |harness: &mut Harness| {
+                harness
+                    .content_state()
+                    .drag
+                    .as_ref()
+                    .map(|d| d.preview_target)
+            };
+            assert_eq!(target(&mut harness), Some(away));
+
+            // Back over the origin, on either side of its center.
+            for x in [
+                tabs[2].x + 1.0,
+                tabs[2].center_x(),
+                tabs[2].x + tabs[2].width - 1.0,
+            ] {
+                let back = Point::new(x, tabs[2].center_y());
+                let _ = harness.move_to(back);
+                assert_eq!(target(&mut harness), Some(2));
+            }
+            let back = tabs[2].center();
+            assert_eq!(harness.release(back), vec![], "returning from tab {away}");
+        }
+    }
+
+    #[test]
+    fn out_of_range_snapshots_leave_no_tab_active() {
+        let restore = |active| {
+            TabBar::<Message, usize, Theme, ()>::from_snapshot(
+                TabBarSnapshot {
+                    order: vec![7, 8, 9],
+                    active,
+                },
+                |id| TabLabel::Text(format!("Tab {id}")),
+                Message::Select,
+            )
+        };
+
+        let restored = restore(Some(1));
+        assert_eq!(restored.get_active_tab_idx(), Some(1));
+        assert_eq!(restored.get_active_tab_id(), Some(&8));
+
+        let restored = restore(Some(5));
+        assert_eq!(restored.get_active_tab_idx(), None);
+        assert_eq!(restored.get_active_tab_id(), None);
+        assert_eq!(restored.snapshot().active, None);
+    }
+
+    #[test]
+    fn active_getters_agree_when_the_index_names_no_tab() {
+        let empty = bar(&[]);
+        assert_eq!(empty.get_active_tab_idx(), None);
+        assert_eq!(empty.get_active_tab_id(), None);
+
+        let filled = bar(&[4, 5]);
+        assert_eq!(filled.get_active_tab_idx(), Some(0));
+        assert_eq!(filled.get_active_tab_id(), Some(&4));
+    }
+
+    #[test]
+    fn touch_drags_report_their_moves() {
+        let mut harness = Harness::new(
+            bar(&[0, 1, 2])
+                .on_reorder(Message::Move)
+                .on_drag_move(Message::DragMove)
+                .touch_reorder_delay(Duration::ZERO),
+        );
+        let tabs = harness.tab_bounds();
+        let _ = harness.touch(tabs[0].center());
+        let _ = harness.touch_move(tabs[1].center());
+        let moved = harness.touch_move(tabs[2].center());
+
+        assert!(
+            moved
+                .iter()
+                .any(|message| matches!(message, Message::DragMove(0, _)))
+        );
+        let overlay_pos = harness.content_state().drag.as_ref().map(|d| d.overlay_pos);
+        assert_eq!(overlay_pos, Some(tabs[2].center()));
+    }
+}